@@ -0,0 +1,59 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use lordeckcodes::{encoder, CardCodeAndCount, Deck};
+
+/// Decks of the reference test data, which covers every set, faction and count.
+fn test_data_decks() -> Vec<Deck> {
+    include_str!("../tests/DeckCodesTestData.txt")
+        .split("\n\n")
+        .filter_map(|block| block.lines().next())
+        .filter(|code| !code.is_empty())
+        .map(|code| encoder::deck_from_code(code).unwrap())
+        .collect()
+}
+
+/// A deck with 40 different cards spread over many sets and factions.
+fn singleton_deck() -> Deck {
+    let factions = ["DE", "FR", "IO", "NX", "PZ", "SI", "BW", "SH"];
+    Deck::from_vec(
+        (0..40)
+            .map(|i| {
+                let code = format!("0{}{}{:03}", i % 5 + 1, factions[i % 8], i + 1);
+                CardCodeAndCount::from_data(&code, 1).unwrap()
+            })
+            .collect(),
+    )
+}
+
+fn encode(c: &mut Criterion) {
+    let decks = test_data_decks();
+    c.bench_function("code_from_deck/test data", |b| {
+        b.iter(|| {
+            for deck in &decks {
+                black_box(encoder::code_from_deck(black_box(deck)).unwrap());
+            }
+        })
+    });
+
+    let deck = singleton_deck();
+    c.bench_function("code_from_deck/40 singletons", |b| {
+        b.iter(|| encoder::code_from_deck(black_box(&deck)).unwrap())
+    });
+}
+
+fn decode(c: &mut Criterion) {
+    let codes: Vec<String> = test_data_decks()
+        .iter()
+        .map(|deck| encoder::code_from_deck(deck).unwrap())
+        .collect();
+    c.bench_function("deck_from_code/test data", |b| {
+        b.iter(|| {
+            for code in &codes {
+                black_box(encoder::deck_from_code(black_box(code)).unwrap());
+            }
+        })
+    });
+}
+
+criterion_group!(benches, encode, decode);
+criterion_main!(benches);
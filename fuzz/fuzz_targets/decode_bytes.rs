@@ -0,0 +1,25 @@
+#![no_main]
+
+use data_encoding::BASE32_NOPAD;
+use libfuzzer_sys::fuzz_target;
+use lordeckcodes::encoder;
+
+// decodes the bytes of a code directly, without going through invalid base32 first
+fuzz_target!(|bytes: &[u8]| {
+    let code = BASE32_NOPAD.encode(bytes);
+    let Ok(deck) = encoder::deck_from_code(&code) else {
+        assert!(encoder::decode_with(&code, |_, _| {}).is_err());
+        return;
+    };
+
+    let mut visited = 0;
+    encoder::decode_with(&code, |_, _| visited += 1).unwrap();
+    assert_eq!(visited, deck.cards().len());
+
+    let mut buffer = vec![None; deck.cards().len()];
+    let len = encoder::decode_into(&code, &mut buffer).unwrap();
+    assert_eq!(len, buffer.len());
+    assert!(buffer.iter().flatten().eq(deck.cards()));
+
+    encoder::code_from_deck(&deck).unwrap();
+});
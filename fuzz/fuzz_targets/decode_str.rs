@@ -0,0 +1,19 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use lordeckcodes::{encoder, CardCodeAndCount, Deck};
+
+fuzz_target!(|code: &str| {
+    if let Ok(deck) = encoder::deck_from_code(code) {
+        encoder::code_from_deck(&deck).unwrap();
+    }
+
+    let _ = encoder::normalize_code(code);
+    for _ in encoder::find_codes(code) {}
+
+    // the same strings as card codes
+    if let Ok(card_count) = CardCodeAndCount::from_data(code, 1) {
+        assert_eq!(card_count.card().to_string(), code);
+    }
+    let _ = Deck::new().add_from_data(code, 1);
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use lordeckcodes::{encoder, CardCodeAndCount, Deck};
+
+// unlike the decks of `round_trip`, these may hold the same card several times
+fuzz_target!(|cards: Vec<CardCodeAndCount>| {
+    let deck = Deck::from_vec(cards);
+    if let Ok(code) = encoder::code_from_deck(&deck) {
+        encoder::deck_from_code(&code).unwrap();
+    }
+});
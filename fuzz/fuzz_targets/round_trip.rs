@@ -0,0 +1,16 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use lordeckcodes::{encoder, Deck};
+
+fuzz_target!(|deck: Deck| {
+    let code = encoder::code_from_deck(&deck).unwrap();
+    let decoded = encoder::deck_from_code(&code).unwrap();
+
+    let mut expected = deck.cards().clone();
+    let mut actual = decoded.cards().clone();
+    expected.sort();
+    actual.sort();
+    assert_eq!(actual, expected);
+    assert_eq!(encoder::normalize_code(&code).unwrap(), code);
+});
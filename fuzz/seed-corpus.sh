@@ -0,0 +1,13 @@
+#!/bin/sh
+# Seeds the corpus of the decoding targets with the codes of tests/DeckCodesTestData.txt.
+set -e
+cd "$(dirname "$0")"
+mkdir -p corpus/decode_str corpus/decode_bytes
+
+grep -E '^[A-Z2-7]+$' ../tests/DeckCodesTestData.txt | while read -r code; do
+    printf '%s' "$code" > "corpus/decode_str/$code"
+    # base32 -d expects the padding that deck codes leave out
+    padding=$(( (8 - ${#code} % 8) % 8 ))
+    printf '%s%s' "$code" "$(printf '%*s' "$padding" '' | tr ' ' '=')" \
+        | base32 -d > "corpus/decode_bytes/$code"
+done
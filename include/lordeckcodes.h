@@ -0,0 +1,110 @@
+#ifndef LORDECKCODES_H
+#define LORDECKCODES_H
+
+/* Generated by cbindgen from src/ffi.rs, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Result of a library call, mirroring the variants of [`LorError`].
+typedef enum {
+  LOR_ERROR_CODE_OK = 0,
+  LOR_ERROR_CODE_NULL_POINTER,
+  LOR_ERROR_CODE_INVALID_UTF8,
+  LOR_ERROR_CODE_DECODE,
+  LOR_ERROR_CODE_VARINT_DECODE,
+  LOR_ERROR_CODE_INVALID_CARD_CODE,
+  LOR_ERROR_CODE_INVALID_CARD,
+  LOR_ERROR_CODE_INVALID_DECK,
+  LOR_ERROR_CODE_VERSION,
+  LOR_ERROR_CODE_BUFFER_TOO_SMALL,
+  LOR_ERROR_CODE_INVALID_URL,
+  LOR_ERROR_CODE_INDEX_OUT_OF_BOUNDS,
+} LorErrorCode;
+
+// Opaque handle to a [`Deck`].
+//
+// Functions returning a [`LorErrorCode`] leave their output arguments untouched on failure.
+// Handles and strings returned by the library must be released with [`lor_deck_free`] and
+// [`lor_string_free`].
+typedef struct LorDeck LorDeck;
+
+// A card of a deck with its number of copies.
+typedef struct {
+  // NUL-terminated card code, such as `"01SI015"`.
+  char code[8];
+  int32_t count;
+} LorCardCount;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Static description of an error code, or `"unknown error"` if `code` is not the value of
+// a [`LorErrorCode`].
+//
+// The code is taken as an `int` so that any value coming from C is valid.
+const char *lor_error_message(int code);
+
+// Create a new empty deck, to be released with [`lor_deck_free`].
+LorDeck *lor_deck_new(void);
+
+// Release a deck. Does nothing if `deck` is null.
+//
+// # Safety
+//
+// `deck` must be null or a handle returned by this library that was not released yet.
+void lor_deck_free(LorDeck *deck);
+
+// Decode `code` into a new deck stored in `out_deck`, to be released with
+// [`lor_deck_free`].
+//
+// # Safety
+//
+// `code` must be a NUL-terminated string and `out_deck` a valid pointer.
+LorErrorCode lor_deck_from_code(const char *code, LorDeck **out_deck);
+
+// Generate the code of `deck`, stored in `out_code` as a NUL-terminated string to be
+// released with [`lor_string_free`].
+//
+// # Safety
+//
+// `deck` must be a valid handle and `out_code` a valid pointer.
+LorErrorCode lor_deck_to_code(const LorDeck *deck, char **out_code);
+
+// Add `count` copies of the card with the given code to `deck`.
+//
+// # Safety
+//
+// `deck` must be a valid handle and `card_code` a NUL-terminated string.
+LorErrorCode lor_deck_add(LorDeck *deck, const char *card_code, int32_t count);
+
+// Number of entries in `deck`, or 0 if `deck` is null.
+//
+// # Safety
+//
+// `deck` must be null or a valid handle.
+size_t lor_deck_len(const LorDeck *deck);
+
+// Store the entry of `deck` at `index` in `out_card`.
+//
+// # Safety
+//
+// `deck` must be a valid handle and `out_card` a valid pointer.
+LorErrorCode lor_deck_get(const LorDeck *deck, size_t index, LorCardCount *out_card);
+
+// Release a string returned by this library. Does nothing if `s` is null.
+//
+// # Safety
+//
+// `s` must be null or a string returned by this library that was not released yet.
+void lor_string_free(char *s);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* LORDECKCODES_H */
@@ -0,0 +1,45 @@
+use std::collections::BTreeMap;
+
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::card::{factions, Card, CardCodeAndCount};
+use crate::deck::Deck;
+
+/// Cards with a known faction, a set from 0 to 99 and a number from 0 to 999.
+impl<'a> Arbitrary<'a> for Card {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let factions: Vec<u32> = factions().collect();
+        Ok(Card::new(
+            u.int_in_range(0..=99)?,
+            *u.choose(&factions)?,
+            u.int_in_range(0..=999)?,
+        ))
+    }
+}
+
+/// Counts mostly from 1 to 3 but sometimes up to 40, which decks encode separately.
+impl<'a> Arbitrary<'a> for CardCodeAndCount {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let card = Card::arbitrary(u)?;
+        let count = if u.ratio(1, 10)? {
+            u.int_in_range(4..=40)?
+        } else {
+            u.int_in_range(1..=3)?
+        };
+
+        Ok(CardCodeAndCount::new(card, count))
+    }
+}
+
+/// Decks holding each card at most once.
+impl<'a> Arbitrary<'a> for Deck {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut cards = BTreeMap::new();
+        for card_count in u.arbitrary_iter::<CardCodeAndCount>()? {
+            let card_count = card_count?;
+            cards.entry(card_count.card().clone()).or_insert(card_count);
+        }
+
+        Ok(Deck::from_vec(cards.into_values().collect()))
+    }
+}
@@ -0,0 +1,83 @@
+use data_encoding::{DecodeError, DecodeKind};
+
+/// Bits carried by a single base32 symbol.
+const SYMBOL_BITS: usize = 5;
+
+/// Decodes an unpadded base32 string one byte at a time, without allocating.
+///
+/// The input is validated when created, following the rules of `BASE32_NOPAD` and reporting
+/// the same errors, so iterating never fails.
+#[derive(Debug, Clone)]
+pub(crate) struct Base32Bytes<'a> {
+    input: &'a [u8],
+    buffer: u32,
+    bits: usize,
+}
+
+impl<'a> Base32Bytes<'a> {
+    pub(crate) fn new(input: &'a str) -> Result<Self, DecodeError> {
+        let input = input.as_bytes();
+        let fail = |position, kind| DecodeError { position, kind };
+
+        if matches!(input.len() % 8, 1 | 3 | 6) {
+            return Err(fail(input.len() - 1, DecodeKind::Length));
+        }
+
+        if let Some(position) = input.iter().position(|&c| symbol_value(c).is_none()) {
+            return Err(fail(position, DecodeKind::Symbol));
+        }
+
+        // the unused low bits of the last symbol must be zero
+        let trailing = SYMBOL_BITS * input.len() % 8;
+        if let Some(&last) = input.last() {
+            if symbol_value(last).unwrap_or(0) & ((1 << trailing) - 1) != 0 {
+                return Err(fail(input.len() - 1, DecodeKind::Trailing));
+            }
+        }
+
+        Ok(Base32Bytes {
+            input,
+            buffer: 0,
+            bits: 0,
+        })
+    }
+
+    /// Whether all the bytes have been decoded.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Iterator for Base32Bytes<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        while self.bits < 8 {
+            let (&symbol, rest) = self.input.split_first()?;
+            self.input = rest;
+            self.buffer = (self.buffer << SYMBOL_BITS) | u32::from(symbol_value(symbol)?);
+            self.bits += SYMBOL_BITS;
+        }
+
+        self.bits -= 8;
+        let byte = (self.buffer >> self.bits) as u8;
+        self.buffer &= (1 << self.bits) - 1;
+
+        Some(byte)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.bits + SYMBOL_BITS * self.input.len()) / 8;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Base32Bytes<'_> {}
+
+fn symbol_value(c: u8) -> Option<u8> {
+    match c {
+        b'A'..=b'Z' => Some(c - b'A'),
+        b'2'..=b'7' => Some(c - b'2' + 26),
+        _ => None,
+    }
+}
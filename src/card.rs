@@ -1,47 +1,65 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(any(feature = "serde", feature = "sqlx", feature = "rusqlite"))]
+use alloc::string::{String, ToString};
+use core::fmt;
+
 use crate::encoder::MAX_KNOWN_VERSION;
 use crate::error::LorError;
-use std::collections::HashMap;
-
-lazy_static! {
-    static ref FACTION_TO_INT: HashMap<&'static str, u32> = {
-        let mut map = HashMap::new();
-        map.insert("DE", 0);
-        map.insert("FR", 1);
-        map.insert("IO", 2);
-        map.insert("NX", 3);
-        map.insert("PZ", 4);
-        map.insert("SI", 5);
-        map.insert("BW", 6);
-        map.insert("SH", 7);
-        map.insert("MT", 9);
-        map.insert("BC", 10);
-        map.insert("RU", 12);
-        map
+
+fn faction_to_int(faction: &str) -> Option<u32> {
+    factions().find(|&number| int_to_faction(number) == Some(faction))
+}
+
+fn int_to_faction(number: u32) -> Option<&'static str> {
+    let faction = match number {
+        0 => "DE",
+        1 => "FR",
+        2 => "IO",
+        3 => "NX",
+        4 => "PZ",
+        5 => "SI",
+        6 => "BW",
+        7 => "SH",
+        9 => "MT",
+        10 => "BC",
+        12 => "RU",
+        _ => return None,
     };
+
+    Some(faction)
+}
+
+/// Largest faction number known by `int_to_faction`.
+const MAX_FACTION: u32 = 12;
+
+/// Numbers of the known factions, in increasing order.
+pub(crate) fn factions() -> impl Iterator<Item = u32> {
+    (0..=MAX_FACTION).filter(|&number| int_to_faction(number).is_some())
 }
 
-lazy_static! {
-    static ref FACTION_NUMBER_TO_VERSION: HashMap<u32, u8> = {
-        let mut map = HashMap::new();
-        map.insert(0, 1);
-        map.insert(1, 1);
-        map.insert(2, 1);
-        map.insert(3, 1);
-        map.insert(4, 1);
-        map.insert(5, 1);
-        map.insert(6, 2);
-        map.insert(9, 2);
-        map.insert(7, 3);
-        map.insert(10, 4);
-        map.insert(12, 5);
-        map
+pub(crate) fn faction_number_to_version(number: u32) -> Option<u8> {
+    let version = match number {
+        0..=5 => 1,
+        6 | 9 => 2,
+        7 => 3,
+        10 => 4,
+        12 => 5,
+        _ => return None,
     };
+
+    Some(version)
 }
 
+/// A card, identified by its set, faction and number.
+///
+/// Deserializing a `Card` checks that it has a known faction and fits in a card code, as
+/// [`CardCodeAndCount::from_data`] does.
+///
+/// [`CardCodeAndCount::from_data`]: struct.CardCodeAndCount.html#method.from_data
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawCard"))]
 #[derive(PartialEq, Hash, Debug, Clone, Ord, PartialOrd, Eq)]
 pub struct Card {
     set: u32,
@@ -49,6 +67,29 @@ pub struct Card {
     number: u32,
 }
 
+/// Unvalidated fields of a deserialized [`Card`].
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawCard {
+    set: u32,
+    faction: u32,
+    number: u32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawCard> for Card {
+    type Error = LorError;
+
+    fn try_from(raw: RawCard) -> Result<Self, Self::Error> {
+        // a card code holds two digits for the set and three for the number
+        if raw.set > 99 || raw.number > 999 || int_to_faction(raw.faction).is_none() {
+            return Err(LorError::InvalidCard);
+        }
+
+        Ok(Card::new(raw.set, raw.faction, raw.number))
+    }
+}
+
 impl Card {
     pub(crate) fn new(set: u32, faction: u32, number: u32) -> Card {
         Card {
@@ -59,23 +100,32 @@ impl Card {
     }
 
     pub(crate) fn from_code(code: &str) -> Result<Card, LorError> {
-        let faction = FACTION_TO_INT.get(&code[2..4]);
+        // checked before slicing, which panics outside of the string or within a character
+        let bytes = code.as_bytes();
+        if bytes.len() != 7
+            || !bytes[0..2].iter().all(u8::is_ascii_digit)
+            || !bytes[4..7].iter().all(u8::is_ascii_digit)
+        {
+            return Err(LorError::InvalidCard);
+        }
+
+        let faction = faction_to_int(&code[2..4]);
         if faction.is_none() {
             return Err(LorError::InvalidCard);
         }
 
         Ok(Card {
-            set: (&code[0..2]).parse()?,
-            faction: *faction.unwrap(),
-            number: (&code[4..7]).parse()?,
+            set: code[0..2].parse()?,
+            faction: faction.unwrap(),
+            number: code[4..7].parse()?,
         })
     }
 
     pub(crate) fn get_version(&self) -> u8 {
-        let version = FACTION_NUMBER_TO_VERSION.get(&self.faction);
+        let version = faction_number_to_version(self.faction);
 
         if let Some(v) = version {
-            *v
+            v
         } else {
             MAX_KNOWN_VERSION
         }
@@ -92,16 +142,95 @@ impl Card {
     pub fn number(&self) -> u32 {
         self.number
     }
+
+    /// The two letter faction identifier (e.g. `"SI"`), if the faction is known.
+    pub fn faction_code(&self) -> Option<&'static str> {
+        int_to_faction(self.faction)
+    }
+
+    /// The card code, which only exists for cards of known factions.
+    #[cfg(any(feature = "serde", feature = "sqlx", feature = "rusqlite"))]
+    pub(crate) fn code(&self) -> Result<String, LorError> {
+        if self.faction_code().is_none() {
+            return Err(LorError::InvalidCard);
+        }
+        Ok(self.to_string())
+    }
+
+    /// The faction identifier, or the faction number for unknown factions.
+    #[cfg(feature = "std")]
+    pub(crate) fn faction_key(&self) -> String {
+        match self.faction_code() {
+            Some(faction) => faction.to_string(),
+            None => self.faction.to_string(),
+        }
+    }
+
+    /// The name of the card's region (e.g. `"Shadow Isles"`), if the faction is known.
+    pub fn region_name(&self) -> Option<&'static str> {
+        let name = match self.faction_code()? {
+            "DE" => "Demacia",
+            "FR" => "Freljord",
+            "IO" => "Ionia",
+            "NX" => "Noxus",
+            "PZ" => "Piltover & Zaun",
+            "SI" => "Shadow Isles",
+            "BW" => "Bilgewater",
+            "SH" => "Shurima",
+            "MT" => "Targon",
+            "BC" => "Bandle City",
+            "RU" => "Runeterra",
+            _ => return None,
+        };
+
+        Some(name)
+    }
+}
+
+/// Formats the card as its card code (e.g. `01SI015`).
+///
+/// Cards with an unknown faction have the faction number written in its place.
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.faction_code() {
+            Some(faction) => write!(f, "{:02}{}{:03}", self.set, faction, self.number),
+            None => write!(f, "{:02}{:02}{:03}", self.set, self.faction, self.number),
+        }
+    }
 }
 
 /// Stores card-related information.
+///
+/// The count is always at least one, which deserializing a `CardCodeAndCount` also checks.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawCardCodeAndCount"))]
 #[derive(Debug, PartialEq, Hash, Clone, Ord, PartialOrd, Eq)]
 pub struct CardCodeAndCount {
     card: Card,
     count: i32,
 }
 
+/// Unvalidated fields of a deserialized [`CardCodeAndCount`].
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawCardCodeAndCount {
+    card: Card,
+    count: i32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawCardCodeAndCount> for CardCodeAndCount {
+    type Error = LorError;
+
+    fn try_from(raw: RawCardCodeAndCount) -> Result<Self, Self::Error> {
+        if raw.count < 1 {
+            return Err(LorError::InvalidCard);
+        }
+
+        Ok(CardCodeAndCount::new(raw.card, raw.count))
+    }
+}
+
 impl CardCodeAndCount {
     pub(crate) fn new(card: Card, count: i32) -> CardCodeAndCount {
         CardCodeAndCount { card, count }
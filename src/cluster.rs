@@ -0,0 +1,381 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+
+use crate::card::{Card, CardCodeAndCount};
+use crate::deck::Deck;
+use crate::encoder;
+use crate::error::LorError;
+
+/// Parameters used by [`cluster_decks`].
+///
+/// [`cluster_decks`]: fn.cluster_decks.html
+#[derive(Debug, Clone)]
+pub struct ClusterOptions {
+    /// Maximum number of clusters to produce.
+    pub k: usize,
+    /// Maximum number of assignment and medoid update rounds.
+    pub max_iterations: usize,
+    /// Minimum share of a cluster's decks a card must appear in to be reported as a core card.
+    pub core_threshold: f64,
+}
+
+impl ClusterOptions {
+    /// Create options looking for `k` clusters, with default iteration and core card limits.
+    pub fn new(k: usize) -> Self {
+        ClusterOptions {
+            k,
+            max_iterations: 100,
+            core_threshold: 0.9,
+        }
+    }
+}
+
+/// A card played by only part of the decks of a [`Cluster`].
+///
+/// [`Cluster`]: struct.Cluster.html
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlexSlot {
+    card: Card,
+    play_rate: f64,
+    average_count: f64,
+}
+
+impl FlexSlot {
+    pub fn card(&self) -> &Card {
+        &self.card
+    }
+
+    /// Share of the cluster's decks that include the card.
+    pub fn play_rate(&self) -> f64 {
+        self.play_rate
+    }
+
+    /// Average number of copies in the decks that include the card.
+    pub fn average_count(&self) -> f64 {
+        self.average_count
+    }
+}
+
+/// A group of similar decks found by [`cluster_decks`].
+///
+/// [`cluster_decks`]: fn.cluster_decks.html
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cluster {
+    medoid: usize,
+    members: Vec<usize>,
+    core: Vec<CardCodeAndCount>,
+    flex: Vec<FlexSlot>,
+    representative_code: String,
+}
+
+impl Cluster {
+    /// Index of the deck that best represents the cluster.
+    pub fn medoid(&self) -> usize {
+        self.medoid
+    }
+
+    /// Indices of the decks belonging to the cluster.
+    pub fn members(&self) -> &[usize] {
+        &self.members
+    }
+
+    /// Cards played by most of the cluster, with their most common count.
+    pub fn core(&self) -> &[CardCodeAndCount] {
+        &self.core
+    }
+
+    /// Remaining cards, ordered by decreasing play rate.
+    pub fn flex(&self) -> &[FlexSlot] {
+        &self.flex
+    }
+
+    /// Code of the medoid deck.
+    pub fn representative_code(&self) -> &str {
+        &self.representative_code
+    }
+}
+
+/// Groups decks into archetypes using k-medoids over their card counts.
+///
+/// The distance between two decks is the number of card copies that differ between them.
+/// Deck indices in the returned clusters refer to positions in `decks`, and clusters are
+/// ordered by decreasing size. Fewer than `k` clusters are returned when there are not
+/// enough distinct decks.
+///
+/// # Examples
+/// ```
+/// use lordeckcodes::cluster::{cluster_decks, ClusterOptions};
+/// use lordeckcodes::encoder;
+///
+/// let decks = vec![
+///     encoder::deck_from_code("CEBAIAIFB4WDANQIAEAQGDAUDAQSIJZUAIAQCAIEAEAQKBIA").unwrap(),
+///     encoder::deck_from_code("CEBAEAIBAQTQMAIAAILSQLBNGUBACAIBFYDACAAHBEHR2IBLAEBACAIFAY").unwrap(),
+/// ];
+///
+/// let clusters = cluster_decks(&decks, &ClusterOptions::new(2)).unwrap();
+/// assert_eq!(clusters.len(), 2);
+/// ```
+pub fn cluster_decks(decks: &[Deck], options: &ClusterOptions) -> Result<Vec<Cluster>, LorError> {
+    let vectors: Vec<HashMap<&Card, i32>> = decks.iter().map(Deck::counts).collect();
+
+    let mut medoids = initial_medoids(&vectors, options.k);
+    let mut assignment = assign(&vectors, &medoids);
+
+    for _ in 0..options.max_iterations {
+        let updated: Vec<usize> = medoids
+            .iter()
+            .enumerate()
+            .map(|(cluster, &medoid)| best_medoid(&vectors, &assignment, cluster, medoid))
+            .collect();
+
+        if updated == medoids {
+            break;
+        }
+
+        medoids = updated;
+        assignment = assign(&vectors, &medoids);
+    }
+
+    let mut clusters = vec![];
+    for (cluster, &medoid) in medoids.iter().enumerate() {
+        let members: Vec<usize> = (0..decks.len())
+            .filter(|&i| assignment[i] == cluster)
+            .collect();
+        if members.is_empty() {
+            continue;
+        }
+
+        let (core, flex) = summarize(&vectors, &members, options.core_threshold);
+        clusters.push(Cluster {
+            medoid,
+            members,
+            core,
+            flex,
+            representative_code: encoder::code_from_deck(&decks[medoid])?,
+        });
+    }
+
+    clusters.sort_by_key(|c| Reverse(c.members.len()));
+
+    Ok(clusters)
+}
+
+fn distance(a: &HashMap<&Card, i32>, b: &HashMap<&Card, i32>) -> i64 {
+    let from_a: i64 = a
+        .iter()
+        .map(|(card, &count)| (i64::from(count) - i64::from(*b.get(card).unwrap_or(&0))).abs())
+        .sum();
+    let only_b: i64 = b
+        .iter()
+        .filter(|(card, _)| !a.contains_key(*card))
+        .map(|(_, &count)| i64::from(count).abs())
+        .sum();
+
+    from_a + only_b
+}
+
+fn initial_medoids(vectors: &[HashMap<&Card, i32>], k: usize) -> Vec<usize> {
+    if vectors.is_empty() || k == 0 {
+        return vec![];
+    }
+
+    // farthest-first traversal, starting from the first deck
+    let mut medoids = vec![0];
+    let mut nearest: Vec<i64> = vectors.iter().map(|v| distance(v, &vectors[0])).collect();
+
+    while medoids.len() < k {
+        let (farthest, &max) = nearest.iter().enumerate().max_by_key(|(_, &d)| d).unwrap();
+        if max == 0 {
+            break;
+        }
+
+        medoids.push(farthest);
+        for (i, v) in vectors.iter().enumerate() {
+            nearest[i] = nearest[i].min(distance(v, &vectors[farthest]));
+        }
+    }
+
+    medoids
+}
+
+fn assign(vectors: &[HashMap<&Card, i32>], medoids: &[usize]) -> Vec<usize> {
+    vectors
+        .iter()
+        .map(|v| {
+            medoids
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, &m)| distance(v, &vectors[m]))
+                .map(|(cluster, _)| cluster)
+                .unwrap()
+        })
+        .collect()
+}
+
+fn best_medoid(
+    vectors: &[HashMap<&Card, i32>],
+    assignment: &[usize],
+    cluster: usize,
+    current: usize,
+) -> usize {
+    let members: Vec<usize> = (0..vectors.len())
+        .filter(|&i| assignment[i] == cluster)
+        .collect();
+    let cost = |candidate: usize| -> i64 {
+        members
+            .iter()
+            .map(|&m| distance(&vectors[candidate], &vectors[m]))
+            .sum()
+    };
+
+    // keep the current medoid on ties so that the iteration converges
+    let mut best = (cost(current), current);
+    for &candidate in &members {
+        let candidate_cost = cost(candidate);
+        if candidate_cost < best.0 {
+            best = (candidate_cost, candidate);
+        }
+    }
+
+    best.1
+}
+
+fn summarize(
+    vectors: &[HashMap<&Card, i32>],
+    members: &[usize],
+    core_threshold: f64,
+) -> (Vec<CardCodeAndCount>, Vec<FlexSlot>) {
+    let mut count_frequencies: HashMap<&Card, HashMap<i32, usize>> = HashMap::new();
+    for &member in members {
+        for (&card, &count) in &vectors[member] {
+            *count_frequencies
+                .entry(card)
+                .or_default()
+                .entry(count)
+                .or_insert(0) += 1;
+        }
+    }
+
+    let mut core = vec![];
+    let mut flex = vec![];
+    for (card, frequencies) in count_frequencies {
+        let decks: usize = frequencies.values().sum();
+        let play_rate = decks as f64 / members.len() as f64;
+
+        if play_rate >= core_threshold {
+            let (&count, _) = frequencies
+                .iter()
+                .max_by(|a, b| a.1.cmp(b.1).then(b.0.cmp(a.0)))
+                .unwrap();
+            core.push(CardCodeAndCount::new(card.clone(), count));
+        } else {
+            let copies: f64 = frequencies
+                .iter()
+                .map(|(&count, &n)| f64::from(count) * n as f64)
+                .sum();
+            flex.push(FlexSlot {
+                card: card.clone(),
+                play_rate,
+                average_count: copies / decks as f64,
+            });
+        }
+    }
+
+    core.sort();
+    flex.sort_by(|a, b| {
+        b.play_rate
+            .partial_cmp(&a.play_rate)
+            .unwrap()
+            .then_with(|| a.card.cmp(&b.card))
+    });
+
+    (core, flex)
+}
+
+/// Builds the consensus list of a group of decks, typically the members of a [`Cluster`].
+///
+/// Copies of cards are picked in order of how many decks play at least that many copies,
+/// while keeping the deck within [`Deck::SIZE`] cards, [`Deck::MAX_COPIES`] copies of each
+/// card and [`Deck::MAX_CHAMPIONS`] copies of cards in `champions`. The result has fewer
+/// than [`Deck::SIZE`] cards only if the decks do not provide enough distinct copies.
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+///
+/// use lordeckcodes::cluster::consensus_deck;
+/// use lordeckcodes::Deck;
+///
+/// let decks: Vec<Deck> = vec![
+///     [("01SI015", 3), ("01SI044", 2)].iter().collect(),
+///     [("01SI015", 2), ("01SI044", 2)].iter().collect(),
+///     [("01SI015", 3), ("01SI048", 1)].iter().collect(),
+/// ];
+///
+/// let expected: Deck = [("01SI015", 3), ("01SI044", 2), ("01SI048", 1)].iter().collect();
+/// assert_eq!(consensus_deck(&decks, &HashSet::new()), expected);
+/// ```
+///
+/// [`Cluster`]: struct.Cluster.html
+/// [`Deck::SIZE`]: ../struct.Deck.html#associatedconstant.SIZE
+/// [`Deck::MAX_COPIES`]: ../struct.Deck.html#associatedconstant.MAX_COPIES
+/// [`Deck::MAX_CHAMPIONS`]: ../struct.Deck.html#associatedconstant.MAX_CHAMPIONS
+pub fn consensus_deck(decks: &[Deck], champions: &HashSet<Card>) -> Deck {
+    // number of decks playing at least `copy` copies of each card
+    let mut slots: HashMap<(&Card, i32), usize> = HashMap::new();
+    for deck in decks {
+        for (card, count) in deck.counts() {
+            for copy in 1..=count.min(Deck::MAX_COPIES) {
+                *slots.entry((card, copy)).or_insert(0) += 1;
+            }
+        }
+    }
+
+    let mut slots: Vec<((&Card, i32), usize)> = slots.into_iter().collect();
+    slots.sort_by(|((card_a, copy_a), decks_a), ((card_b, copy_b), decks_b)| {
+        decks_b
+            .cmp(decks_a)
+            .then_with(|| card_a.cmp(card_b))
+            .then(copy_a.cmp(copy_b))
+    });
+
+    let mut size = 0;
+    let mut champion_count = 0;
+    let mut counts: HashMap<&Card, i32> = HashMap::new();
+    for ((card, _), _) in slots {
+        if size == Deck::SIZE {
+            break;
+        }
+
+        let is_champion = champions.contains(card);
+        if is_champion && champion_count == Deck::MAX_CHAMPIONS {
+            continue;
+        }
+
+        *counts.entry(card).or_insert(0) += 1;
+        size += 1;
+        if is_champion {
+            champion_count += 1;
+        }
+    }
+
+    let mut cards: Vec<CardCodeAndCount> = counts
+        .into_iter()
+        .map(|(card, count)| CardCodeAndCount::new(card.clone(), count))
+        .collect();
+    cards.sort();
+
+    Deck::from_vec(cards)
+}
+
+/// Builds the [`consensus_deck`] of a group of decks and returns its code.
+///
+/// [`consensus_deck`]: fn.consensus_deck.html
+pub fn consensus_code(decks: &[Deck], champions: &HashSet<Card>) -> Result<String, LorError> {
+    encoder::code_from_deck(&consensus_deck(decks, champions))
+}
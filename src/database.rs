@@ -0,0 +1,234 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer};
+
+use std::collections::{HashMap, HashSet};
+use std::iter::FromIterator;
+
+use crate::card::Card;
+use crate::error::LorError;
+
+/// Metadata of a single card.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardInfo {
+    card: Card,
+    name: String,
+    cost: u32,
+    champion: bool,
+}
+
+impl CardInfo {
+    /// Create a `CardInfo` from the provided code and card data.
+    pub fn new(code: &str, name: &str, cost: u32, champion: bool) -> Result<CardInfo, LorError> {
+        if code.len() != 7 {
+            return Err(LorError::InvalidCard);
+        }
+
+        Ok(CardInfo {
+            card: Card::from_code(code)?,
+            name: name.to_string(),
+            cost,
+            champion,
+        })
+    }
+
+    pub fn card(&self) -> &Card {
+        &self.card
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn cost(&self) -> u32 {
+        self.cost
+    }
+
+    pub fn is_champion(&self) -> bool {
+        self.champion
+    }
+}
+
+/// A local collection of [`CardInfo`], indexed by card and by name.
+///
+/// With the `serde` feature, a `CardDatabase` can be deserialized from a set file of the
+/// official Data Dragon bundles. Tokens and other uncollectible cards are skipped.
+///
+/// # Examples
+/// ```
+/// use lordeckcodes::database::{CardDatabase, CardInfo};
+///
+/// let mut database = CardDatabase::new();
+/// database.insert(CardInfo::new("01SI053", "Elise", 2, true).unwrap());
+///
+/// assert_eq!(database.find_by_name("elise").unwrap().cost(), 2);
+/// assert_eq!(database.suggest("Elsie", 3)[0].name(), "Elise");
+/// ```
+///
+/// [`CardInfo`]: struct.CardInfo.html
+#[derive(Debug, Clone, Default)]
+pub struct CardDatabase {
+    cards: HashMap<Card, CardInfo>,
+    names: HashMap<String, Card>,
+}
+
+impl CardDatabase {
+    /// Create a new empty `CardDatabase`.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Add a card to the database, replacing any previous metadata of the same card.
+    ///
+    /// When two cards share a name, name lookups resolve to the first one inserted.
+    pub fn insert(&mut self, info: CardInfo) {
+        let card = info.card().clone();
+        let name = normalize_name(info.name());
+
+        if let Some(previous) = self.cards.insert(card.clone(), info) {
+            let previous_name = normalize_name(previous.name());
+            if self.names.get(&previous_name) == Some(&card) {
+                self.names.remove(&previous_name);
+                // hand the old name over to another card that still has it
+                if let Some(other) = self
+                    .cards
+                    .values()
+                    .find(|other| normalize_name(other.name()) == previous_name)
+                {
+                    self.names.insert(previous_name, other.card().clone());
+                }
+            }
+        }
+
+        self.names.entry(name).or_insert(card);
+    }
+
+    pub fn get(&self, card: &Card) -> Option<&CardInfo> {
+        self.cards.get(card)
+    }
+
+    /// Look up a card by name, ignoring case, whitespace and punctuation.
+    pub fn find_by_name(&self, name: &str) -> Option<&CardInfo> {
+        self.names
+            .get(&normalize_name(name))
+            .and_then(|card| self.cards.get(card))
+    }
+
+    /// Cards whose names are close to `name`, closest first, for reporting typos.
+    pub fn suggest(&self, name: &str, limit: usize) -> Vec<&CardInfo> {
+        let name = normalize_name(name);
+        let max_distance = (name.chars().count() / 4).max(2);
+
+        let mut candidates: Vec<(usize, &CardInfo)> = self
+            .names
+            .iter()
+            .map(|(candidate, card)| (levenshtein(&name, candidate), &self.cards[card]))
+            .filter(|(distance, _)| *distance <= max_distance)
+            .collect();
+        candidates.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.name.cmp(&b.1.name)));
+
+        candidates
+            .into_iter()
+            .take(limit)
+            .map(|(_, info)| info)
+            .collect()
+    }
+
+    /// The set of cards flagged as champions.
+    pub fn champions(&self) -> HashSet<Card> {
+        self.cards
+            .values()
+            .filter(|info| info.champion)
+            .map(|info| info.card.clone())
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// Iterate over the metadata of all cards, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = &CardInfo> {
+        self.cards.values()
+    }
+}
+
+impl FromIterator<CardInfo> for CardDatabase {
+    fn from_iter<T: IntoIterator<Item = CardInfo>>(iter: T) -> Self {
+        let mut database = CardDatabase::new();
+        database.extend(iter);
+        database
+    }
+}
+
+impl Extend<CardInfo> for CardDatabase {
+    fn extend<T: IntoIterator<Item = CardInfo>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|info| self.insert(info));
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DataDragonCard {
+    card_code: String,
+    name: String,
+    cost: u32,
+    #[serde(default)]
+    rarity_ref: String,
+    #[serde(default = "collectible_default")]
+    collectible: bool,
+}
+
+#[cfg(feature = "serde")]
+fn collectible_default() -> bool {
+    true
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for CardDatabase {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let cards = Vec::<DataDragonCard>::deserialize(deserializer)?;
+
+        Ok(cards
+            .into_iter()
+            .filter(|card| card.collectible)
+            .filter_map(|card| {
+                CardInfo::new(
+                    &card.card_code,
+                    &card.name,
+                    card.cost,
+                    card.rarity_ref == "Champion",
+                )
+                .ok()
+            })
+            .collect())
+    }
+}
+
+pub(crate) fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
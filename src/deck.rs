@@ -3,16 +3,29 @@ use serde::{Deserialize, Serialize};
 
 use crate::card::{Card, CardCodeAndCount};
 use crate::error::LorError;
-use std::iter::FromIterator;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::FromIterator;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
 
 /// Holds a set of [`CardCodeAndCount`].
 ///
 /// [`CardCodeAndCount`]: struct.CardCodeAndCount.html
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct Deck(Vec<CardCodeAndCount>);
 
 impl Deck {
+    /// Number of cards in a constructed deck.
+    pub const SIZE: i32 = 40;
+    /// Maximum number of copies of a card in a constructed deck.
+    pub const MAX_COPIES: i32 = 3;
+    /// Maximum number of champion cards in a constructed deck.
+    pub const MAX_CHAMPIONS: i32 = 6;
+
     /// Create a new empty `Deck`.
     pub fn new() -> Self {
         Default::default()
@@ -50,6 +63,163 @@ impl Deck {
     pub fn cards(&self) -> &Vec<CardCodeAndCount> {
         &self.0
     }
+
+    /// Total count of each card, merging entries that refer to the same card.
+    ///
+    /// Totals saturate at the bounds of `i32`.
+    #[cfg(feature = "std")]
+    pub(crate) fn counts(&self) -> HashMap<&Card, i32> {
+        self.0
+            .iter()
+            .fold(HashMap::new(), |mut counts, card_count| {
+                let count = counts.entry(card_count.card()).or_insert(0);
+                *count = count.saturating_add(card_count.count());
+                counts
+            })
+    }
+
+    /// Total count of each faction identifier, or faction number for unknown factions.
+    ///
+    /// Totals saturate at the bounds of `i32`.
+    #[cfg(feature = "std")]
+    pub(crate) fn region_counts(&self) -> BTreeMap<String, i32> {
+        self.0
+            .iter()
+            .fold(BTreeMap::new(), |mut counts, card_count| {
+                let count = counts.entry(card_count.card().faction_key()).or_insert(0);
+                *count = count.saturating_add(card_count.count());
+                counts
+            })
+    }
+
+    /// Total number of copies of `card`, over all the entries referring to it.
+    ///
+    /// The total saturates at the bounds of `i32`.
+    pub fn count(&self, card: &Card) -> i32 {
+        self.0
+            .iter()
+            .filter(|card_count| card_count.card() == card)
+            .fold(0, |total, card_count| {
+                total.saturating_add(card_count.count())
+            })
+    }
+
+    /// Replace the entries referring to `card` with a single entry of `count` copies, or
+    /// remove them if `count` is less than one.
+    ///
+    /// The card keeps the position of its first entry, and is added last if it was missing.
+    ///
+    /// # Examples
+    /// ```
+    /// use lordeckcodes::Deck;
+    /// let mut deck: Deck = [("01SI015", 1), ("01SI044", 3), ("01SI015", 1)].iter().collect();
+    /// let card = deck.cards()[0].card().clone();
+    ///
+    /// deck.set_count(card.clone(), 3);
+    /// assert_eq!(deck, Deck::from_iter(&[("01SI015", 3), ("01SI044", 3)]));
+    ///
+    /// deck.set_count(card, 0);
+    /// assert_eq!(deck, Deck::from_iter(&[("01SI044", 3)]));
+    /// ```
+    pub fn set_count(&mut self, card: Card, count: i32) {
+        let position = self
+            .0
+            .iter()
+            .position(|card_count| card_count.card() == &card);
+        self.0.retain(|card_count| card_count.card() != &card);
+        if count < 1 {
+            return;
+        }
+
+        let card_count = CardCodeAndCount::new(card, count);
+        match position {
+            Some(i) => self.0.insert(i, card_count),
+            None => self.0.push(card_count),
+        }
+    }
+
+    /// The cards to add and remove to turn this deck into `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use lordeckcodes::Deck;
+    /// let before: Deck = [("01SI015", 3), ("01SI044", 1)].iter().collect();
+    /// let after: Deck = [("01SI015", 1), ("01SI048", 2)].iter().collect();
+    ///
+    /// let diff = before.diff(&after);
+    /// assert_eq!(diff.added(), Deck::from_iter(&[("01SI048", 2)]).cards().as_slice());
+    /// assert_eq!(
+    ///     diff.removed(),
+    ///     Deck::from_iter(&[("01SI015", 2), ("01SI044", 1)]).cards().as_slice()
+    /// );
+    /// ```
+    pub fn diff(&self, other: &Deck) -> DeckDiff {
+        let mut changes: BTreeMap<&Card, i32> = BTreeMap::new();
+        for card_count in &self.0 {
+            *changes.entry(card_count.card()).or_insert(0) -= card_count.count();
+        }
+        for card_count in &other.0 {
+            *changes.entry(card_count.card()).or_insert(0) += card_count.count();
+        }
+
+        let mut diff = DeckDiff::default();
+        for (card, change) in changes {
+            match change.cmp(&0) {
+                Ordering::Greater => diff.added.push(CardCodeAndCount::new(card.clone(), change)),
+                Ordering::Less => diff
+                    .removed
+                    .push(CardCodeAndCount::new(card.clone(), -change)),
+                Ordering::Equal => {}
+            }
+        }
+
+        diff
+    }
+}
+
+/// Changes between two decks, as computed by [`Deck::diff`].
+///
+/// Both lists hold each card once, ordered by card, with the number of copies added or removed.
+///
+/// [`Deck::diff`]: struct.Deck.html#method.diff
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DeckDiff {
+    added: Vec<CardCodeAndCount>,
+    removed: Vec<CardCodeAndCount>,
+}
+
+impl DeckDiff {
+    /// Cards with more copies in the new deck.
+    pub fn added(&self) -> &[CardCodeAndCount] {
+        &self.added
+    }
+
+    /// Cards with fewer copies in the new deck.
+    pub fn removed(&self) -> &[CardCodeAndCount] {
+        &self.removed
+    }
+
+    /// Whether both decks hold the same cards.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Formats the removed then the added cards, such as `-2 01SI015 +2 01SI044`.
+impl fmt::Display for DeckDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let removed = self.removed.iter().map(|card_count| ('-', card_count));
+        let added = self.added.iter().map(|card_count| ('+', card_count));
+        for (i, (sign, card_count)) in removed.chain(added).enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{sign}{} {}", card_count.count(), card_count.card())?;
+        }
+
+        Ok(())
+    }
 }
 
 impl PartialEq for Deck {
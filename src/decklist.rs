@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+
+use crate::card::{Card, CardCodeAndCount};
+use crate::database::CardDatabase;
+use crate::deck::Deck;
+
+/// Maximum number of suggestions reported for an unresolved card name.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// A line of a decklist that could not be turned into a card, or that would take a card over
+/// the copy limit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnresolvedLine {
+    line: usize,
+    text: String,
+    suggestions: Vec<String>,
+}
+
+impl UnresolvedLine {
+    /// One-based line number in the parsed text.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Names of known cards close to the name on the line, closest first.
+    pub fn suggestions(&self) -> &[String] {
+        &self.suggestions
+    }
+}
+
+/// Result of [`parse_decklist`].
+///
+/// [`parse_decklist`]: fn.parse_decklist.html
+#[derive(Debug)]
+pub struct DecklistImport {
+    deck: Deck,
+    unresolved: Vec<UnresolvedLine>,
+}
+
+impl DecklistImport {
+    /// The cards that were resolved.
+    pub fn deck(&self) -> &Deck {
+        &self.deck
+    }
+
+    pub fn into_deck(self) -> Deck {
+        self.deck
+    }
+
+    pub fn unresolved(&self) -> &[UnresolvedLine] {
+        &self.unresolved
+    }
+
+    /// Whether every line was resolved.
+    pub fn is_complete(&self) -> bool {
+        self.unresolved.is_empty()
+    }
+}
+
+/// Parses a plain-text decklist, resolving card names through `database`.
+///
+/// Each non-empty line holds a count and a card, written as `3x Ezreal`, `3 Ezreal`,
+/// `Ezreal x3` or with a card code in place of the name (`3 01PZ036`). Names are matched
+/// ignoring case, whitespace and punctuation. Lines starting with `#` or `//` are ignored.
+/// Counts of cards listed on several lines are added up, and a line taking a card over
+/// [`Deck::MAX_COPIES`] copies is reported as unresolved.
+///
+/// # Examples
+/// ```
+/// use lordeckcodes::database::{CardDatabase, CardInfo};
+/// use lordeckcodes::decklist::parse_decklist;
+///
+/// let mut database = CardDatabase::new();
+/// database.insert(CardInfo::new("01PZ036", "Ezreal", 3, true).unwrap());
+/// database.insert(CardInfo::new("01PZ052", "Mystic Shot", 2, false).unwrap());
+///
+/// let import = parse_decklist("3x Ezreal\n2 mystic shot\n1 Mystic Shoot", &database);
+///
+/// assert_eq!(import.deck().cards().len(), 2);
+/// assert_eq!(import.unresolved()[0].line(), 3);
+/// assert_eq!(import.unresolved()[0].suggestions(), &["Mystic Shot"]);
+/// ```
+///
+/// [`Deck::MAX_COPIES`]: ../struct.Deck.html#associatedconstant.MAX_COPIES
+pub fn parse_decklist(text: &str, database: &CardDatabase) -> DecklistImport {
+    let mut order: Vec<Card> = vec![];
+    let mut counts: HashMap<Card, i32> = HashMap::new();
+    let mut unresolved = vec![];
+
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with("//") {
+            continue;
+        }
+
+        let resolved = split_count(line)
+            .and_then(|(count, name)| resolve(name, database).map(|card| (card, count)));
+
+        match resolved {
+            Some((card, count)) => {
+                let total = counts
+                    .get(&card)
+                    .map_or(Some(count), |total| total.checked_add(count))
+                    .filter(|&total| total <= Deck::MAX_COPIES);
+                match total {
+                    Some(total) => {
+                        if counts.insert(card.clone(), total).is_none() {
+                            order.push(card);
+                        }
+                    }
+                    None => unresolved.push(UnresolvedLine {
+                        line: i + 1,
+                        text: line.to_string(),
+                        suggestions: vec![],
+                    }),
+                }
+            }
+            None => {
+                let name = split_count(line).map_or(line, |(_, name)| name);
+                unresolved.push(UnresolvedLine {
+                    line: i + 1,
+                    text: line.to_string(),
+                    suggestions: database
+                        .suggest(name, MAX_SUGGESTIONS)
+                        .into_iter()
+                        .map(|info| info.name().to_string())
+                        .collect(),
+                });
+            }
+        }
+    }
+
+    let cards = order
+        .into_iter()
+        .map(|card| {
+            let count = counts[&card];
+            CardCodeAndCount::new(card, count)
+        })
+        .collect();
+
+    DecklistImport {
+        deck: Deck::from_vec(cards),
+        unresolved,
+    }
+}
+
+fn split_count(line: &str) -> Option<(i32, &str)> {
+    fn parse_count(count: &str) -> Option<i32> {
+        let count = count
+            .trim_end_matches(['x', 'X'])
+            .trim_start_matches(['x', 'X']);
+        count.parse().ok().filter(|&count| count > 0)
+    }
+
+    let (first, rest) = line.split_once(char::is_whitespace)?;
+    if let Some(count) = parse_count(first) {
+        let rest = rest.trim_start();
+        let rest = match rest.split_once(char::is_whitespace) {
+            Some((x, name)) if x.eq_ignore_ascii_case("x") => name,
+            _ => rest,
+        };
+        return Some((count, rest.trim()));
+    }
+
+    let (rest, last) = line.rsplit_once(char::is_whitespace)?;
+    parse_count(last).map(|count| (count, rest.trim()))
+}
+
+fn resolve(name: &str, database: &CardDatabase) -> Option<Card> {
+    if let Some(info) = database.find_by_name(name) {
+        return Some(info.card().clone());
+    }
+
+    if name.len() == 7 && name.is_ascii() {
+        CardCodeAndCount::from_data(name, 1)
+            .ok()
+            .map(|card_count| card_count.card().clone())
+    } else {
+        None
+    }
+}
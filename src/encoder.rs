@@ -1,10 +1,13 @@
-use std::io::Cursor;
-
-use varint_rs::{VarintReader, VarintWriter};
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
 
+use crate::base32::Base32Bytes;
 use crate::card::{Card, CardCodeAndCount};
 use crate::deck::Deck;
 use crate::error::LorError;
+use crate::varint;
 
 const FORMAT: u8 = 1;
 pub(crate) const MAX_KNOWN_VERSION: u8 = 5;
@@ -26,48 +29,232 @@ pub fn deck_from_code<S>(code: S) -> Result<Deck, LorError>
 where
     S: AsRef<str>,
 {
-    let mut bytes = data_encoding::BASE32_NOPAD.decode(code.as_ref().as_bytes())?;
+    let mut cards = vec![];
+    decode_with(code, |card, count| {
+        cards.push(CardCodeAndCount::new(card, count))
+    })?;
 
-    if bytes.is_empty() {
-        return Err(LorError::InvalidCard);
-    }
+    Ok(Deck::from_vec(cards))
+}
+
+/// Decodes a code, calling `visit` with each card and its count in the order they are
+/// stored, without allocating.
+///
+/// If the code turns out to be malformed part way, `visit` may have been called for the
+/// cards preceding the error.
+///
+/// # Examples
+/// ```
+/// use lordeckcodes::encoder;
+///
+/// let mut total = 0;
+/// encoder::decode_with(
+///     "CEBAIAIFB4WDANQIAEAQGDAUDAQSIJZUAIAQCAIEAEAQKBIA",
+///     |_card, count| total += count,
+/// )
+/// .unwrap();
+/// assert_eq!(total, 40);
+/// ```
+pub fn decode_with<S, F>(code: S, mut visit: F) -> Result<(), LorError>
+where
+    S: AsRef<str>,
+    F: FnMut(Card, i32),
+{
+    let mut bytes = Base32Bytes::new(code.as_ref())?;
 
-    let _format = bytes[0] >> 4;
-    let version = bytes[0] & 0xF;
-    bytes.remove(0);
+    let header = bytes.next().ok_or(LorError::InvalidCard)?;
+    let _format = header >> 4;
+    let version = header & 0xF;
     if version > MAX_KNOWN_VERSION {
         return Err(LorError::Version);
     }
 
-    let mut cards = vec![];
-    let mut cursor = Cursor::new(bytes);
     for i in (1..=3).rev() {
-        let num_groups = cursor.read_u32_varint()?;
+        let num_groups = varint::read_u32(&mut bytes)?;
 
         for _j in 0..num_groups {
-            let num_this_group = cursor.read_u32_varint()?;
-            let set = cursor.read_u32_varint()?;
-            let faction = cursor.read_u32_varint()?;
+            let num_this_group = varint::read_u32(&mut bytes)?;
+            let set = varint::read_u32(&mut bytes)?;
+            let faction = varint::read_u32(&mut bytes)?;
 
             for _k in 0..num_this_group {
-                let card_num = cursor.read_u32_varint()?;
-                let card_count = CardCodeAndCount::new(Card::new(set, faction, card_num), i);
-                cards.push(card_count);
+                let card_num = varint::read_u32(&mut bytes)?;
+                visit(Card::new(set, faction, card_num), i);
             }
         }
     }
 
-    while cursor.position() < cursor.get_ref().len() as u64 {
-        let count = cursor.read_u32_varint()?;
-        let set = cursor.read_u32_varint()?;
-        let faction = cursor.read_u32_varint()?;
-        let number = cursor.read_u32_varint()?;
+    while !bytes.is_empty() {
+        // rejected like `code_from_deck` does, so that decoded decks can be encoded again
+        let count = match i32::try_from(varint::read_u32(&mut bytes)?) {
+            Ok(count) if count > 0 => count,
+            _ => return Err(LorError::InvalidDeck),
+        };
+        let set = varint::read_u32(&mut bytes)?;
+        let faction = varint::read_u32(&mut bytes)?;
+        let number = varint::read_u32(&mut bytes)?;
+
+        visit(Card::new(set, faction, number), count);
+    }
+
+    Ok(())
+}
 
-        let card_count = CardCodeAndCount::new(Card::new(set, faction, number), count as i32);
-        cards.push(card_count);
+/// Decodes a code into a caller-provided buffer, returning the number of cards written.
+///
+/// The first slots of `buffer` are set to the cards of the deck and the others are left
+/// untouched. Fails with [`LorError::BufferTooSmall`] if the deck has more cards than `buffer`,
+/// in which case, as for any other error, `buffer` is not modified.
+///
+/// [`LorError::BufferTooSmall`]: ../enum.LorError.html#variant.BufferTooSmall
+///
+/// # Examples
+/// ```
+/// use lordeckcodes::{encoder, CardCodeAndCount};
+///
+/// let mut buffer: [Option<CardCodeAndCount>; 40] = core::array::from_fn(|_| None);
+/// let len = encoder::decode_into(
+///     "CEBAIAIFB4WDANQIAEAQGDAUDAQSIJZUAIAQCAIEAEAQKBIA",
+///     &mut buffer,
+/// )
+/// .unwrap();
+/// assert_eq!(len, 14);
+/// assert_eq!(buffer[0].as_ref().unwrap().card().to_string(), "01SI015");
+/// ```
+pub fn decode_into<S>(code: S, buffer: &mut [Option<CardCodeAndCount>]) -> Result<usize, LorError>
+where
+    S: AsRef<str>,
+{
+    // decode once to validate the code and count its cards before writing any slot
+    let mut needed = 0;
+    decode_with(code.as_ref(), |_, _| needed += 1)?;
+    if needed > buffer.len() {
+        return Err(LorError::BufferTooSmall { needed });
     }
 
-    Ok(Deck::from_vec(cards))
+    let mut slots = buffer.iter_mut();
+    decode_with(code, |card, count| {
+        if let Some(slot) = slots.next() {
+            *slot = Some(CardCodeAndCount::new(card, count));
+        }
+    })?;
+
+    Ok(needed)
+}
+
+/// Rewrites a code in its canonical form, as generated by [`code_from_deck`].
+///
+/// Whitespace and padding are removed and letters are uppercased before decoding, so codes
+/// pasted from chats or URLs are accepted. Two codes of the same deck have the same
+/// canonical form, whatever the order of their cards.
+///
+/// [`code_from_deck`]: fn.code_from_deck.html
+///
+/// # Examples
+/// ```
+/// use lordeckcodes::encoder;
+///
+/// let code = encoder::normalize_code(" cebaiaifb4wdanqiaeaqgdaudaqsijzu\naiaqcaieaeaqkbia== ");
+/// assert_eq!(
+///     code.unwrap(),
+///     "CEBAIAIFB4WDANQIAEAQGDAUDAQSIJZUAIAQCAIEAEAQKBIA"
+/// );
+/// ```
+pub fn normalize_code<S>(code: S) -> Result<String, LorError>
+where
+    S: AsRef<str>,
+{
+    let code: String = code
+        .as_ref()
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '=')
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+
+    code_from_deck(&deck_from_code(code)?)
+}
+
+/// Finds the deck codes embedded in free-form text.
+///
+/// Yields the byte range of each code in `text` together with its decoded [`Deck`]. Codes
+/// are recognized inside URLs, quotes or Markdown, but not as part of a longer word.
+/// Candidates that do not decode to a plausible deck, i.e. one with cards from known
+/// factions only, are skipped, which rules out most uppercase words and acronyms.
+///
+/// [`Deck`]: struct.Deck.html
+///
+/// # Examples
+/// ```
+/// use lordeckcodes::encoder;
+///
+/// let text = "try `CEBAIAIFB4WDANQIAEAQGDAUDAQSIJZUAIAQCAIEAEAQKBIA`, IT IS AMAZING";
+/// let codes: Vec<_> = encoder::find_codes(text).collect();
+///
+/// assert_eq!(codes.len(), 1);
+/// assert_eq!(codes[0].0, 5..53);
+/// assert_eq!(codes[0].1.cards().len(), 14);
+/// ```
+pub fn find_codes(text: &str) -> impl Iterator<Item = (Range<usize>, Deck)> + '_ {
+    // shortest code possible: a single card with a count of one
+    const MIN_CODE_LEN: usize = 13;
+
+    fn is_code_char(c: u8) -> bool {
+        c.is_ascii_uppercase() || (b'2'..=b'7').contains(&c)
+    }
+
+    fn is_plausible(deck: &Deck) -> bool {
+        !deck.cards().is_empty()
+            && deck.cards().iter().all(|card_count| {
+                let card = card_count.card();
+                (1..=Deck::SIZE).contains(&card_count.count())
+                    && card.set() > 0
+                    && card.number() > 0
+                    && card.faction_code().is_some()
+            })
+    }
+
+    let bytes = text.as_bytes();
+    let mut position = 0;
+
+    core::iter::from_fn(move || {
+        while position < bytes.len() {
+            let start = position;
+            if !is_code_char(bytes[start]) {
+                position += 1;
+                continue;
+            }
+
+            let mut end = start;
+            while end < bytes.len() && is_code_char(bytes[end]) {
+                end += 1;
+            }
+            while end < bytes.len() && bytes[end].is_ascii_alphanumeric() {
+                end += 1;
+            }
+            position = end;
+
+            let candidate = &bytes[start..end];
+            let standalone = (start == 0 || !bytes[start - 1].is_ascii_alphanumeric())
+                && candidate.iter().all(|&c| is_code_char(c));
+
+            // the first character holds the format, which is always 1
+            if !standalone
+                || candidate.len() < MIN_CODE_LEN
+                || candidate[0] != b'C'
+                || !matches!(candidate.len() % 8, 0 | 2 | 4 | 5 | 7)
+            {
+                continue;
+            }
+
+            if let Ok(deck) = deck_from_code(&text[start..end]) {
+                if is_plausible(&deck) {
+                    return Some((start..end, deck));
+                }
+            }
+        }
+
+        None
+    })
 }
 
 /// Generate a code from the provided [`Deck`].
@@ -126,104 +313,53 @@ pub fn code_from_deck(deck: &Deck) -> Result<String, LorError> {
 
     for card_code in deck.cards() {
         match card_code.count() {
-            3 => of3.push(card_code.clone()),
-            2 => of2.push(card_code.clone()),
-            1 => of1.push(card_code.clone()),
+            3 => of3.push(card_code.card()),
+            2 => of2.push(card_code.card()),
+            1 => of1.push(card_code.card()),
             n if n < 1 => return Err(LorError::InvalidDeck),
-            _ => ofn.push(card_code.clone()),
+            _ => ofn.push(card_code),
         }
     }
 
-    let mut grouped_of3 = group_by_set_and_faction(&mut of3);
-    let mut grouped_of2 = group_by_set_and_faction(&mut of2);
-    let mut grouped_of1 = group_by_set_and_faction(&mut of1);
-
-    sort_group(&mut grouped_of3);
-    sort_group(&mut grouped_of2);
-    sort_group(&mut grouped_of1);
-
     ofn.sort_by_key(|x| x.count());
 
-    bytes.extend(encode_group(&grouped_of3)?);
-    bytes.extend(encode_group(&grouped_of2)?);
-    bytes.extend(encode_group(&grouped_of1)?);
-    bytes.extend(encode_rest(&ofn)?);
+    encode_group(&mut bytes, &mut of3);
+    encode_group(&mut bytes, &mut of2);
+    encode_group(&mut bytes, &mut of1);
+    encode_rest(&mut bytes, &ofn);
 
-    Ok(data_encoding::BASE32_NOPAD.encode(&*bytes))
+    Ok(data_encoding::BASE32_NOPAD.encode(&bytes))
 }
 
-fn group_by_set_and_faction(cards: &mut Vec<CardCodeAndCount>) -> Vec<Vec<CardCodeAndCount>> {
-    let mut result = vec![];
-
-    while !cards.is_empty() {
-        let ref_card_count = cards.remove(0);
-        let ref_card = ref_card_count.card();
-
-        let mut current_set = vec![];
-        for card_count in cards.iter_mut() {
-            if card_count.card().set() == ref_card.set()
-                && card_count.card().faction() == ref_card.faction()
-            {
-                current_set.push(card_count.clone());
-            }
-        }
-
-        cards.retain(|x| {
-            !(x.card().set() == ref_card.set() && x.card().faction() == ref_card.faction())
-        });
-
-        current_set.push(ref_card_count);
-
-        result.push(current_set);
-    }
-
-    result
-}
-
-fn sort_group(group: &mut [Vec<CardCodeAndCount>]) {
-    group.sort_by(|a, b| {
-        let ordering = a.len().cmp(&b.len());
-        match ordering {
-            std::cmp::Ordering::Equal => a[0].card().cmp(b[0].card()),
-            _ => ordering,
-        }
-    });
-
-    group
-        .iter_mut()
-        .for_each(|cards| cards.sort_by_key(|x| x.card().number()));
-}
-
-fn encode_group(group: &[Vec<CardCodeAndCount>]) -> Result<Vec<u8>, LorError> {
-    let bytes = vec![];
-    let mut cursor = Cursor::new(bytes);
-    cursor.write_u32_varint(group.len() as u32)?;
-
-    for cards in group {
-        cursor.write_u32_varint(cards.len() as u32)?;
-
-        let ref_card = &cards.first().unwrap().card();
-        cursor.write_u32_varint(ref_card.set())?;
-        cursor.write_u32_varint(ref_card.faction())?;
-
-        for card_count in cards {
-            cursor.write_u32_varint(card_count.card().number())?;
+/// Writes cards sharing the same count, grouped by set and faction.
+///
+/// Groups are ordered by size, then set and faction, and cards by number within a group.
+fn encode_group(bytes: &mut Vec<u8>, cards: &mut [&Card]) {
+    cards.sort_unstable_by_key(|card| (card.set(), card.faction(), card.number()));
+
+    let mut groups: Vec<&[&Card]> = cards
+        .chunk_by(|a, b| a.set() == b.set() && a.faction() == b.faction())
+        .collect();
+    groups.sort_unstable_by_key(|group| (group.len(), group[0].set(), group[0].faction()));
+
+    varint::write_u32(bytes, groups.len() as u32);
+    for group in groups {
+        varint::write_u32(bytes, group.len() as u32);
+        varint::write_u32(bytes, group[0].set());
+        varint::write_u32(bytes, group[0].faction());
+
+        for card in group {
+            varint::write_u32(bytes, card.number());
         }
     }
-
-    Ok(cursor.into_inner())
 }
 
-fn encode_rest(group: &[CardCodeAndCount]) -> Result<Vec<u8>, LorError> {
-    let bytes = vec![];
-    let mut cursor = Cursor::new(bytes);
-
-    for card_count in group {
-        cursor.write_u32_varint(card_count.count() as u32)?;
-        cursor.write_u32_varint(card_count.card().set())?;
-        cursor.write_u32_varint(card_count.card().faction())?;
-        cursor.write_u32_varint(card_count.card().number())?;
+/// Writes cards with a count greater than three, each with its count.
+fn encode_rest(bytes: &mut Vec<u8>, cards: &[&CardCodeAndCount]) {
+    for card_count in cards {
+        varint::write_u32(bytes, card_count.count() as u32);
+        varint::write_u32(bytes, card_count.card().set());
+        varint::write_u32(bytes, card_count.card().faction());
+        varint::write_u32(bytes, card_count.card().number());
     }
-
-    Ok(cursor.into_inner())
 }
@@ -3,15 +3,27 @@ use thiserror::Error;
 #[derive(Debug, Error)]
 pub enum LorError {
     #[error("decode")]
-    Decode(#[from] data_encoding::DecodeError),
+    Decode(#[cfg_attr(feature = "std", from)] data_encoding::DecodeError),
     #[error("varint decode")]
-    VarintDecode(#[from] std::io::Error),
+    VarintDecode(#[from] crate::varint::VarintError),
     #[error("invalid card code")]
-    InvalidCardCode(#[from] std::num::ParseIntError),
+    InvalidCardCode(#[from] core::num::ParseIntError),
     #[error("invalid card")]
     InvalidCard,
     #[error("invalid deck")]
     InvalidDeck,
     #[error("version error")]
     Version,
+    #[error("buffer too small, {needed} cards needed")]
+    BufferTooSmall { needed: usize },
+    #[error("invalid url")]
+    InvalidUrl,
+}
+
+// `DecodeError` only implements `Error` with `std`, so it cannot be a source without it.
+#[cfg(not(feature = "std"))]
+impl From<data_encoding::DecodeError> for LorError {
+    fn from(error: data_encoding::DecodeError) -> Self {
+        LorError::Decode(error)
+    }
 }
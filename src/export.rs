@@ -0,0 +1,179 @@
+use std::fmt::Write;
+
+use crate::card::Card;
+use crate::database::CardDatabase;
+use crate::deck::Deck;
+
+/// A card of the exported deck, with whatever metadata is known about it.
+pub(crate) struct Row<'a> {
+    pub(crate) card: &'a Card,
+    pub(crate) name: String,
+    pub(crate) cost: Option<u32>,
+    pub(crate) count: i32,
+}
+
+impl Row<'_> {
+    pub(crate) fn region(&self) -> String {
+        match self.card.region_name() {
+            Some(region) => region.to_string(),
+            None => format!("Faction {}", self.card.faction()),
+        }
+    }
+
+    fn cost(&self) -> String {
+        self.cost.map(|cost| cost.to_string()).unwrap_or_default()
+    }
+}
+
+/// Cards of the deck grouped by region, then sorted by cost and name.
+pub(crate) fn rows<'a>(deck: &'a Deck, database: Option<&CardDatabase>) -> Vec<Row<'a>> {
+    let mut rows: Vec<Row> = deck
+        .counts()
+        .into_iter()
+        .map(|(card, count)| {
+            let info = database.and_then(|database| database.get(card));
+            Row {
+                card,
+                name: info.map_or_else(|| card.to_string(), |info| info.name().to_string()),
+                cost: info.map(|info| info.cost()),
+                count,
+            }
+        })
+        .collect();
+
+    // cards with an unknown cost go last in their region
+    rows.sort_by(|a, b| {
+        a.card
+            .faction()
+            .cmp(&b.card.faction())
+            .then(a.cost.is_none().cmp(&b.cost.is_none()))
+            .then(a.cost.cmp(&b.cost))
+            .then_with(|| a.name.cmp(&b.name))
+            .then_with(|| a.card.cmp(b.card))
+    });
+
+    rows
+}
+
+/// Splits sorted rows into runs of the same region.
+pub(crate) fn regions<'a, 'b>(rows: &'b [Row<'a>]) -> Vec<&'b [Row<'a>]> {
+    let mut regions = vec![];
+    let mut start = 0;
+    for i in 1..=rows.len() {
+        if i == rows.len() || rows[i].card.faction() != rows[start].card.faction() {
+            regions.push(&rows[start..i]);
+            start = i;
+        }
+    }
+
+    regions
+}
+
+/// Escapes text for use in HTML and SVG documents.
+pub(crate) fn escape_markup(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Renders the deck as `3x Name` lines.
+///
+/// Cards missing from `database` are written with their card code.
+///
+/// # Examples
+/// ```
+/// use lordeckcodes::database::{CardDatabase, CardInfo};
+/// use lordeckcodes::{export, Deck};
+///
+/// let mut database = CardDatabase::new();
+/// database.insert(CardInfo::new("01SI053", "Elise", 2, true).unwrap());
+///
+/// let deck: Deck = [("01SI053", 3), ("01SI015", 2)].iter().collect();
+/// assert_eq!(export::to_text(&deck, Some(&database)), "3x Elise\n2x 01SI015\n");
+/// ```
+pub fn to_text(deck: &Deck, database: Option<&CardDatabase>) -> String {
+    rows(deck, database)
+        .iter()
+        .fold(String::new(), |mut text, row| {
+            let _ = writeln!(text, "{}x {}", row.count, row.name);
+            text
+        })
+}
+
+/// Renders the deck as one Markdown table per region.
+pub fn to_markdown(deck: &Deck, database: Option<&CardDatabase>) -> String {
+    let rows = rows(deck, database);
+
+    let mut markdown = String::new();
+    for (i, region) in regions(&rows).into_iter().enumerate() {
+        if i > 0 {
+            markdown.push('\n');
+        }
+
+        let _ = writeln!(markdown, "### {}\n", region[0].region());
+        markdown.push_str("| Cost | Card | Code | Count |\n");
+        markdown.push_str("|-----:|------|------|------:|\n");
+        for row in region {
+            let _ = writeln!(
+                markdown,
+                "| {} | {} | {} | {} |",
+                row.cost(),
+                row.name.replace('|', "\\|"),
+                row.card,
+                row.count
+            );
+        }
+    }
+
+    markdown
+}
+
+/// Renders the deck as CSV with `code,name,count,cost` columns and a header row.
+pub fn to_csv(deck: &Deck, database: Option<&CardDatabase>) -> String {
+    fn escape(field: &str) -> String {
+        if field.contains([',', '"', '\n']) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.to_string()
+        }
+    }
+
+    rows(deck, database)
+        .iter()
+        .fold(String::from("code,name,count,cost\n"), |mut csv, row| {
+            let _ = writeln!(
+                csv,
+                "{},{},{},{}",
+                row.card,
+                escape(&row.name),
+                row.count,
+                row.cost()
+            );
+            csv
+        })
+}
+
+/// Renders the deck as an HTML snippet with a list of cards per region.
+pub fn to_html(deck: &Deck, database: Option<&CardDatabase>) -> String {
+    let rows = rows(deck, database);
+
+    let mut html = String::from("<div class=\"deck\">\n");
+    for region in regions(&rows) {
+        let _ = writeln!(html, "  <h3>{}</h3>", escape_markup(&region[0].region()));
+        html.push_str("  <ul>\n");
+        for row in region {
+            let _ = writeln!(
+                html,
+                "    <li data-code=\"{}\"><span class=\"count\">{}x</span> {}</li>",
+                row.card,
+                row.count,
+                escape_markup(&row.name)
+            );
+        }
+        html.push_str("  </ul>\n");
+    }
+    html.push_str("</div>\n");
+
+    html
+}
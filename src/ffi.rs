@@ -0,0 +1,255 @@
+use std::ffi::{c_char, c_int, CStr, CString};
+
+use crate::card::CardCodeAndCount;
+use crate::deck::Deck;
+use crate::encoder;
+use crate::error::LorError;
+
+/// Opaque handle to a [`Deck`].
+///
+/// Functions returning a [`LorErrorCode`] leave their output arguments untouched on failure.
+/// Handles and strings returned by the library must be released with [`lor_deck_free`] and
+/// [`lor_string_free`].
+pub struct LorDeck(Deck);
+
+/// Result of a library call, mirroring the variants of [`LorError`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LorErrorCode {
+    Ok = 0,
+    NullPointer,
+    InvalidUtf8,
+    Decode,
+    VarintDecode,
+    InvalidCardCode,
+    InvalidCard,
+    InvalidDeck,
+    Version,
+    BufferTooSmall,
+    InvalidUrl,
+    IndexOutOfBounds,
+}
+
+impl From<LorError> for LorErrorCode {
+    fn from(error: LorError) -> Self {
+        match error {
+            LorError::Decode(_) => LorErrorCode::Decode,
+            LorError::VarintDecode(_) => LorErrorCode::VarintDecode,
+            LorError::InvalidCardCode(_) => LorErrorCode::InvalidCardCode,
+            LorError::InvalidCard => LorErrorCode::InvalidCard,
+            LorError::InvalidDeck => LorErrorCode::InvalidDeck,
+            LorError::Version => LorErrorCode::Version,
+            LorError::BufferTooSmall { .. } => LorErrorCode::BufferTooSmall,
+            LorError::InvalidUrl => LorErrorCode::InvalidUrl,
+        }
+    }
+}
+
+/// A card of a deck with its number of copies.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct LorCardCount {
+    /// NUL-terminated card code, such as `"01SI015"`.
+    pub code: [c_char; 8],
+    pub count: i32,
+}
+
+/// Converts a NUL-terminated string argument.
+unsafe fn str_arg<'a>(s: *const c_char) -> Result<&'a str, LorErrorCode> {
+    if s.is_null() {
+        return Err(LorErrorCode::NullPointer);
+    }
+
+    CStr::from_ptr(s)
+        .to_str()
+        .map_err(|_| LorErrorCode::InvalidUtf8)
+}
+
+/// Runs `f`, converting its outcome to an error code.
+fn status(f: impl FnOnce() -> Result<(), LorErrorCode>) -> LorErrorCode {
+    match f() {
+        Ok(()) => LorErrorCode::Ok,
+        Err(code) => code,
+    }
+}
+
+/// Descriptions of the error codes, in the order of their values.
+const ERROR_MESSAGES: [(LorErrorCode, &CStr); 12] = [
+    (LorErrorCode::Ok, c"ok"),
+    (LorErrorCode::NullPointer, c"null pointer"),
+    (LorErrorCode::InvalidUtf8, c"invalid utf-8"),
+    (LorErrorCode::Decode, c"decode"),
+    (LorErrorCode::VarintDecode, c"varint decode"),
+    (LorErrorCode::InvalidCardCode, c"invalid card code"),
+    (LorErrorCode::InvalidCard, c"invalid card"),
+    (LorErrorCode::InvalidDeck, c"invalid deck"),
+    (LorErrorCode::Version, c"version error"),
+    (LorErrorCode::BufferTooSmall, c"buffer too small"),
+    (LorErrorCode::InvalidUrl, c"invalid url"),
+    (LorErrorCode::IndexOutOfBounds, c"index out of bounds"),
+];
+
+/// Static description of an error code, or `"unknown error"` if `code` is not the value of
+/// a [`LorErrorCode`].
+///
+/// The code is taken as an `int` so that any value coming from C is valid.
+#[no_mangle]
+pub extern "C" fn lor_error_message(code: c_int) -> *const c_char {
+    ERROR_MESSAGES
+        .iter()
+        .find(|(error, _)| *error as c_int == code)
+        .map_or(c"unknown error", |(_, message)| message)
+        .as_ptr()
+}
+
+/// Create a new empty deck, to be released with [`lor_deck_free`].
+#[no_mangle]
+pub extern "C" fn lor_deck_new() -> *mut LorDeck {
+    Box::into_raw(Box::new(LorDeck(Deck::new())))
+}
+
+/// Release a deck. Does nothing if `deck` is null.
+///
+/// # Safety
+///
+/// `deck` must be null or a handle returned by this library that was not released yet.
+#[no_mangle]
+pub unsafe extern "C" fn lor_deck_free(deck: *mut LorDeck) {
+    if !deck.is_null() {
+        drop(Box::from_raw(deck));
+    }
+}
+
+/// Decode `code` into a new deck stored in `out_deck`, to be released with
+/// [`lor_deck_free`].
+///
+/// # Safety
+///
+/// `code` must be a NUL-terminated string and `out_deck` a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn lor_deck_from_code(
+    code: *const c_char,
+    out_deck: *mut *mut LorDeck,
+) -> LorErrorCode {
+    status(|| {
+        if out_deck.is_null() {
+            return Err(LorErrorCode::NullPointer);
+        }
+
+        let deck = encoder::deck_from_code(str_arg(code)?)?;
+        *out_deck = Box::into_raw(Box::new(LorDeck(deck)));
+        Ok(())
+    })
+}
+
+/// Generate the code of `deck`, stored in `out_code` as a NUL-terminated string to be
+/// released with [`lor_string_free`].
+///
+/// # Safety
+///
+/// `deck` must be a valid handle and `out_code` a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn lor_deck_to_code(
+    deck: *const LorDeck,
+    out_code: *mut *mut c_char,
+) -> LorErrorCode {
+    status(|| {
+        if deck.is_null() || out_code.is_null() {
+            return Err(LorErrorCode::NullPointer);
+        }
+
+        let code = encoder::code_from_deck(&(*deck).0)?;
+        // base32 codes never contain NUL
+        *out_code = CString::new(code).unwrap().into_raw();
+        Ok(())
+    })
+}
+
+/// Add `count` copies of the card with the given code to `deck`.
+///
+/// # Safety
+///
+/// `deck` must be a valid handle and `card_code` a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn lor_deck_add(
+    deck: *mut LorDeck,
+    card_code: *const c_char,
+    count: i32,
+) -> LorErrorCode {
+    status(|| {
+        if deck.is_null() {
+            return Err(LorErrorCode::NullPointer);
+        }
+
+        let card = CardCodeAndCount::from_data(str_arg(card_code)?, count)?;
+        (*deck).0.add(card);
+        Ok(())
+    })
+}
+
+/// Number of entries in `deck`, or 0 if `deck` is null.
+///
+/// # Safety
+///
+/// `deck` must be null or a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn lor_deck_len(deck: *const LorDeck) -> usize {
+    if deck.is_null() {
+        return 0;
+    }
+
+    (*deck).0.cards().len()
+}
+
+/// Store the entry of `deck` at `index` in `out_card`.
+///
+/// # Safety
+///
+/// `deck` must be a valid handle and `out_card` a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn lor_deck_get(
+    deck: *const LorDeck,
+    index: usize,
+    out_card: *mut LorCardCount,
+) -> LorErrorCode {
+    status(|| {
+        if deck.is_null() || out_card.is_null() {
+            return Err(LorErrorCode::NullPointer);
+        }
+
+        let card_count = (*deck)
+            .0
+            .cards()
+            .get(index)
+            .ok_or(LorErrorCode::IndexOutOfBounds)?;
+
+        // decoded cards may have a set or number too large for a 7 character code
+        let card_code = card_count.card().to_string();
+        if card_code.len() > 7 {
+            return Err(LorErrorCode::InvalidCard);
+        }
+
+        let mut code = [0; 8];
+        for (c, byte) in code.iter_mut().zip(card_code.bytes()) {
+            *c = byte as c_char;
+        }
+
+        *out_card = LorCardCount {
+            code,
+            count: card_count.count(),
+        };
+        Ok(())
+    })
+}
+
+/// Release a string returned by this library. Does nothing if `s` is null.
+///
+/// # Safety
+///
+/// `s` must be null or a string returned by this library that was not released yet.
+#[no_mangle]
+pub unsafe extern "C" fn lor_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
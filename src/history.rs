@@ -0,0 +1,185 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::fmt::Write;
+
+use crate::deck::{Deck, DeckDiff};
+use crate::encoder;
+use crate::error::LorError;
+
+/// A code recorded in a [`DeckHistory`].
+///
+/// [`DeckHistory`]: struct.DeckHistory.html
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryEntry {
+    code: String,
+    timestamp: u64,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    deck: Deck,
+}
+
+impl HistoryEntry {
+    /// Normalized code of the deck.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Timestamp given when the code was recorded.
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    pub fn deck(&self) -> &Deck {
+        &self.deck
+    }
+}
+
+/// The successive codes of one named deck.
+///
+/// Versions are numbered from 1 in the order they were recorded. Timestamps are opaque to the
+/// history, so any unit can be used, such as seconds since the Unix epoch.
+///
+/// With the `serde` feature, a history serializes as its name and the code and timestamp of each
+/// entry. Deserializing records the entries again, rejecting invalid codes.
+///
+/// # Examples
+/// ```
+/// use lordeckcodes::history::DeckHistory;
+/// use lordeckcodes::{encoder, Deck, LorError};
+///
+/// fn main() -> Result<(), LorError> {
+///     let first: Deck = [("01SI015", 3), ("01SI044", 1)].iter().collect();
+///     let second: Deck = [("01SI015", 1), ("01SI044", 3)].iter().collect();
+///
+///     let mut history = DeckHistory::new("Elise Spiders");
+///     history.record(&encoder::code_from_deck(&first)?, 1_700_000_000)?;
+///     history.record(&encoder::code_from_deck(&second)?, 1_700_600_000)?;
+///
+///     assert_eq!(
+///         history.changelog(),
+///         "v1: +3 01SI015 +1 01SI044\nv2: -2 01SI015 +2 01SI044\n"
+///     );
+///     Ok(())
+/// }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawDeckHistory"))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeckHistory {
+    name: String,
+    entries: Vec<HistoryEntry>,
+}
+
+/// Unvalidated fields of a deserialized [`DeckHistory`].
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawDeckHistory {
+    name: String,
+    entries: Vec<RawHistoryEntry>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct RawHistoryEntry {
+    code: String,
+    timestamp: u64,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawDeckHistory> for DeckHistory {
+    type Error = LorError;
+
+    fn try_from(raw: RawDeckHistory) -> Result<Self, Self::Error> {
+        let mut history = DeckHistory::new(&raw.name);
+        for entry in raw.entries {
+            history.record(&entry.code, entry.timestamp)?;
+        }
+
+        Ok(history)
+    }
+}
+
+impl DeckHistory {
+    /// Create an empty history for the deck named `name`.
+    pub fn new(name: &str) -> Self {
+        DeckHistory {
+            name: name.to_string(),
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Record `code` as the next version of the deck, at `timestamp`.
+    ///
+    /// Returns whether a version was added: a code holding the same cards as the latest version
+    /// is not recorded again.
+    pub fn record(&mut self, code: &str, timestamp: u64) -> Result<bool, LorError> {
+        let code = encoder::normalize_code(code)?;
+        let deck = encoder::deck_from_code(&code)?;
+        if self.latest().is_some_and(|latest| latest.deck == deck) {
+            return Ok(false);
+        }
+
+        self.entries.push(HistoryEntry {
+            code,
+            timestamp,
+            deck,
+        });
+        Ok(true)
+    }
+
+    /// Record `deck` as the next version of the deck, at `timestamp`.
+    ///
+    /// See [`record`](#method.record).
+    pub fn record_deck(&mut self, deck: &Deck, timestamp: u64) -> Result<bool, LorError> {
+        self.record(&encoder::code_from_deck(deck)?, timestamp)
+    }
+
+    /// The recorded versions, oldest first.
+    pub fn entries(&self) -> &[HistoryEntry] {
+        &self.entries
+    }
+
+    /// The entry of the given version, starting at 1.
+    pub fn version(&self, version: usize) -> Option<&HistoryEntry> {
+        self.entries.get(version.checked_sub(1)?)
+    }
+
+    pub fn latest(&self) -> Option<&HistoryEntry> {
+        self.entries.last()
+    }
+
+    /// Number of recorded versions.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Changes made by each version, starting with the cards of the first one.
+    pub fn diffs(&self) -> impl Iterator<Item = DeckDiff> + '_ {
+        let previous = std::iter::once(None).chain(self.entries.iter().map(Some));
+        previous
+            .zip(&self.entries)
+            .map(|(previous, entry)| match previous {
+                Some(previous) => previous.deck.diff(&entry.deck),
+                None => Deck::new().diff(&entry.deck),
+            })
+    }
+
+    /// One line per version listing its changes, such as `v3: -2 01SI015 +2 01SI044`.
+    pub fn changelog(&self) -> String {
+        let mut changelog = String::new();
+        for (i, diff) in self.diffs().enumerate() {
+            let _ = writeln!(changelog, "v{}: {diff}", i + 1);
+        }
+
+        changelog
+    }
+}
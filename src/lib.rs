@@ -48,17 +48,82 @@
 //!     Ok(())
 //! }
 //! ```
+//!
+//! # `no_std` support
+//!
+//! Encoding and decoding only require `alloc`. Disabling the default `std` feature builds the
+//! crate as `no_std`, without the modules that need the standard library.
+
+#![cfg_attr(not(feature = "std"), no_std)]
 
-#[macro_use]
-extern crate lazy_static;
+extern crate alloc;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+mod base32;
 mod card;
 mod deck;
 mod error;
+/// Stores decks and cards in TEXT database columns, as their codes.
+#[cfg(any(feature = "rusqlite", feature = "sqlx"))]
+mod sql;
 
+/// Groups decks into archetypes.
+#[cfg(feature = "std")]
+pub mod cluster;
+/// Card metadata used to resolve card names and describe decks.
+#[cfg(feature = "std")]
+pub mod database;
+/// Parses plain-text decklists.
+#[cfg(feature = "std")]
+pub mod decklist;
 /// Provides encode and decode API calls.
 pub mod encoder;
+/// Renders decks as text, Markdown, CSV and HTML.
+#[cfg(feature = "std")]
+pub mod export;
+/// C bindings to decode and encode decks.
+#[cfg(feature = "ffi")]
+pub mod ffi;
+/// Records the successive versions of a deck.
+#[cfg(feature = "std")]
+pub mod history;
+/// Groups the decks a player submits for an event.
+#[cfg(feature = "std")]
+pub mod lineup;
+/// Aggregates statistics over many decks.
+#[cfg(feature = "std")]
+pub mod meta;
+/// Python bindings built with PyO3.
+#[cfg(feature = "python")]
+pub mod python;
+/// Renders deck codes as QR codes.
+#[cfg(feature = "qr")]
+pub mod qr;
+/// Renders SVG images of decks.
+#[cfg(feature = "render")]
+pub mod render;
+/// Compact serde representations of decks and cards.
+#[cfg(feature = "serde")]
+pub mod serde;
+/// Computes bans and series win probabilities of lineups.
+#[cfg(feature = "std")]
+pub mod series;
+/// Saves decks in a local SQLite library and searches them.
+#[cfg(feature = "store")]
+pub mod store;
+/// Proptest strategies generating valid cards and decks.
+#[cfg(feature = "proptest")]
+pub mod strategy;
+/// Reads and creates deck URLs of deck sites.
+#[cfg(feature = "std")]
+pub mod url;
+/// Reads and writes the varints used by deck codes.
+pub mod varint;
+/// JavaScript bindings built with `wasm-bindgen`.
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
-pub use self::card::CardCodeAndCount;
-pub use self::deck::Deck;
+pub use self::card::{Card, CardCodeAndCount};
+pub use self::deck::{Deck, DeckDiff};
 pub use self::error::LorError;
@@ -0,0 +1,212 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use std::collections::{BTreeSet, HashSet};
+use std::fmt;
+
+use crate::card::Card;
+use crate::deck::Deck;
+use crate::encoder;
+use crate::error::LorError;
+
+/// Separator between the deck codes of a lineup code.
+///
+/// It is not part of the base32 alphabet used by deck codes and is safe to use in URLs.
+pub const LINEUP_CODE_SEPARATOR: char = '.';
+
+/// Rules a [`Lineup`] must follow for an event.
+///
+/// [`Lineup`]: struct.Lineup.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineupRules {
+    /// Number of decks in the lineup.
+    pub deck_count: usize,
+    /// Whether two decks may not use cards from the same region.
+    pub unique_regions: bool,
+    /// Whether two decks may not include the same champion.
+    pub unique_champions: bool,
+    /// The cards that are champions.
+    pub champions: HashSet<Card>,
+}
+
+impl LineupRules {
+    /// Rules with `deck_count` decks that may not share regions or champions, as used by
+    /// conquest and last deck standing events.
+    pub fn new(deck_count: usize, champions: HashSet<Card>) -> Self {
+        LineupRules {
+            deck_count,
+            unique_regions: true,
+            unique_champions: true,
+            champions,
+        }
+    }
+}
+
+/// A rule broken by a [`Lineup`].
+///
+/// Deck indices refer to positions in the lineup.
+///
+/// [`Lineup`]: struct.Lineup.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LineupViolation {
+    DeckCount {
+        expected: usize,
+        found: usize,
+    },
+    SharedRegion {
+        region: String,
+        decks: (usize, usize),
+    },
+    SharedChampion {
+        champion: Card,
+        decks: (usize, usize),
+    },
+}
+
+impl fmt::Display for LineupViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LineupViolation::DeckCount { expected, found } => {
+                write!(f, "expected {expected} decks, found {found}")
+            }
+            LineupViolation::SharedRegion { region, decks } => {
+                write!(f, "decks {} and {} share region {region}", decks.0, decks.1)
+            }
+            LineupViolation::SharedChampion { champion, decks } => {
+                write!(
+                    f,
+                    "decks {} and {} share champion {champion}",
+                    decks.0, decks.1
+                )
+            }
+        }
+    }
+}
+
+/// The decks submitted by a player for an event.
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+///
+/// use lordeckcodes::lineup::{Lineup, LineupRules};
+/// use lordeckcodes::Deck;
+///
+/// let lineup = Lineup::new(vec![
+///     [("01SI015", 3), ("01FR003", 3)].iter().collect(),
+///     [("01DE002", 3), ("01IO003", 3)].iter().collect(),
+/// ]);
+/// assert!(lineup.validate(&LineupRules::new(2, HashSet::new())).is_ok());
+///
+/// let code = lineup.to_code().unwrap();
+/// assert_eq!(Lineup::from_code(&code).unwrap().decks().len(), 2);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Lineup(Vec<Deck>);
+
+impl Lineup {
+    /// Create a new `Lineup` from its decks.
+    pub fn new(decks: Vec<Deck>) -> Self {
+        Lineup(decks)
+    }
+
+    pub fn decks(&self) -> &[Deck] {
+        &self.0
+    }
+
+    /// Add a deck to the `Lineup`.
+    pub fn add(&mut self, deck: Deck) {
+        self.0.push(deck);
+    }
+
+    /// Check the lineup against `rules`, reporting every rule that is broken.
+    pub fn validate(&self, rules: &LineupRules) -> Result<(), Vec<LineupViolation>> {
+        let mut violations = vec![];
+
+        if self.0.len() != rules.deck_count {
+            violations.push(LineupViolation::DeckCount {
+                expected: rules.deck_count,
+                found: self.0.len(),
+            });
+        }
+
+        let regions: Vec<BTreeSet<String>> = self
+            .0
+            .iter()
+            .map(|deck| {
+                deck.cards()
+                    .iter()
+                    .map(|c| c.card().faction_key())
+                    .collect()
+            })
+            .collect();
+        let champions: Vec<BTreeSet<&Card>> = self
+            .0
+            .iter()
+            .map(|deck| {
+                deck.cards()
+                    .iter()
+                    .map(|c| c.card())
+                    .filter(|card| rules.champions.contains(card))
+                    .collect()
+            })
+            .collect();
+
+        for i in 0..self.0.len() {
+            for j in i + 1..self.0.len() {
+                if rules.unique_regions {
+                    violations.extend(regions[i].intersection(&regions[j]).map(|region| {
+                        LineupViolation::SharedRegion {
+                            region: region.clone(),
+                            decks: (i, j),
+                        }
+                    }));
+                }
+
+                if rules.unique_champions {
+                    violations.extend(champions[i].intersection(&champions[j]).map(|&champion| {
+                        LineupViolation::SharedChampion {
+                            champion: champion.clone(),
+                            decks: (i, j),
+                        }
+                    }));
+                }
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+
+    /// Generate a single code for the lineup, made of the deck codes joined by
+    /// [`LINEUP_CODE_SEPARATOR`].
+    ///
+    /// [`LINEUP_CODE_SEPARATOR`]: constant.LINEUP_CODE_SEPARATOR.html
+    pub fn to_code(&self) -> Result<String, LorError> {
+        let codes = self
+            .0
+            .iter()
+            .map(encoder::code_from_deck)
+            .collect::<Result<Vec<String>, LorError>>()?;
+
+        Ok(codes.join(&LINEUP_CODE_SEPARATOR.to_string()))
+    }
+
+    /// Creates a `Lineup` from a code generated by [`to_code`].
+    ///
+    /// [`to_code`]: struct.Lineup.html#method.to_code
+    pub fn from_code(code: &str) -> Result<Lineup, LorError> {
+        if code.is_empty() {
+            return Ok(Lineup::default());
+        }
+
+        code.split(LINEUP_CODE_SEPARATOR)
+            .map(encoder::deck_from_code)
+            .collect::<Result<Vec<Deck>, LorError>>()
+            .map(Lineup)
+    }
+}
@@ -0,0 +1,328 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use std::collections::{HashMap, HashSet};
+
+use crate::card::Card;
+use crate::deck::Deck;
+
+/// z-score used for the 95% confidence intervals of win rates.
+const Z_95: f64 = 1.96;
+
+/// Wins and losses of a group of decks, with a 95% Wilson score interval for the win rate.
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct WinRate {
+    wins: u32,
+    losses: u32,
+    rate: f64,
+    lower: f64,
+    upper: f64,
+}
+
+impl WinRate {
+    fn new(wins: u32, losses: u32) -> Option<WinRate> {
+        let games = wins as f64 + losses as f64;
+        if games == 0.0 {
+            return None;
+        }
+
+        let rate = wins as f64 / games;
+        let z2 = Z_95 * Z_95;
+        let center = (rate + z2 / (2.0 * games)) / (1.0 + z2 / games);
+        let margin = Z_95 / (1.0 + z2 / games)
+            * (rate * (1.0 - rate) / games + z2 / (4.0 * games * games)).sqrt();
+
+        Some(WinRate {
+            wins,
+            losses,
+            rate,
+            lower: (center - margin).max(0.0),
+            upper: (center + margin).min(1.0),
+        })
+    }
+
+    pub fn wins(&self) -> u32 {
+        self.wins
+    }
+
+    pub fn losses(&self) -> u32 {
+        self.losses
+    }
+
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// Lower bound of the 95% confidence interval.
+    pub fn lower(&self) -> f64 {
+        self.lower
+    }
+
+    /// Upper bound of the 95% confidence interval.
+    pub fn upper(&self) -> f64 {
+        self.upper
+    }
+}
+
+/// Statistics of a single card across the ingested decks.
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CardStats {
+    card: String,
+    decks: usize,
+    play_rate: f64,
+    average_copies: f64,
+    win_rate: Option<WinRate>,
+}
+
+impl CardStats {
+    /// Card code of the card.
+    pub fn card(&self) -> &str {
+        &self.card
+    }
+
+    /// Number of decks including the card.
+    pub fn decks(&self) -> usize {
+        self.decks
+    }
+
+    /// Share of all decks including the card.
+    pub fn play_rate(&self) -> f64 {
+        self.play_rate
+    }
+
+    /// Average number of copies in the decks including the card.
+    pub fn average_copies(&self) -> f64 {
+        self.average_copies
+    }
+
+    /// Record of the decks including the card, if any results were provided.
+    pub fn win_rate(&self) -> Option<&WinRate> {
+        self.win_rate.as_ref()
+    }
+}
+
+/// Statistics of the decks sharing the same regions or the same champions.
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupStats {
+    key: Vec<String>,
+    decks: usize,
+    play_rate: f64,
+    win_rate: Option<WinRate>,
+}
+
+impl GroupStats {
+    /// Sorted faction identifiers or champion card codes shared by the group.
+    pub fn key(&self) -> &[String] {
+        &self.key
+    }
+
+    /// Number of decks in the group.
+    pub fn decks(&self) -> usize {
+        self.decks
+    }
+
+    /// Share of all decks belonging to the group.
+    pub fn play_rate(&self) -> f64 {
+        self.play_rate
+    }
+
+    /// Record of the group, if any results were provided.
+    pub fn win_rate(&self) -> Option<&WinRate> {
+        self.win_rate.as_ref()
+    }
+}
+
+/// Aggregated statistics over a set of decks, built with [`MetaReportBuilder`].
+///
+/// All lists are ordered by decreasing number of decks.
+///
+/// [`MetaReportBuilder`]: struct.MetaReportBuilder.html
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetaReport {
+    decks: usize,
+    cards: Vec<CardStats>,
+    regions: Vec<GroupStats>,
+    champions: Vec<GroupStats>,
+}
+
+impl MetaReport {
+    /// Create a new `MetaReportBuilder`.
+    pub fn builder() -> MetaReportBuilder {
+        MetaReportBuilder::new()
+    }
+
+    /// Number of ingested decks.
+    pub fn decks(&self) -> usize {
+        self.decks
+    }
+
+    pub fn cards(&self) -> &[CardStats] {
+        &self.cards
+    }
+
+    /// Popularity of each combination of regions.
+    pub fn regions(&self) -> &[GroupStats] {
+        &self.regions
+    }
+
+    /// Popularity of each combination of champions.
+    pub fn champions(&self) -> &[GroupStats] {
+        &self.champions
+    }
+}
+
+#[derive(Debug, Default)]
+struct Tally {
+    decks: usize,
+    copies: i64,
+    wins: u32,
+    losses: u32,
+}
+
+impl Tally {
+    fn add(&mut self, copies: i32, wins: u32, losses: u32) {
+        self.decks += 1;
+        self.copies = self.copies.saturating_add(copies as i64);
+        self.wins = self.wins.saturating_add(wins);
+        self.losses = self.losses.saturating_add(losses);
+    }
+
+    fn merge(&mut self, other: &Tally) {
+        self.decks += other.decks;
+        self.copies = self.copies.saturating_add(other.copies);
+        self.wins = self.wins.saturating_add(other.wins);
+        self.losses = self.losses.saturating_add(other.losses);
+    }
+}
+
+/// Ingests decks, optionally with their results, and produces a [`MetaReport`].
+///
+/// # Examples
+/// ```
+/// use lordeckcodes::encoder;
+/// use lordeckcodes::meta::MetaReport;
+///
+/// let deck =
+///     encoder::deck_from_code("CEBAIAIFB4WDANQIAEAQGDAUDAQSIJZUAIAQCAIEAEAQKBIA").unwrap();
+///
+/// let mut builder = MetaReport::builder();
+/// builder.add_with_record(&deck, 7, 3);
+/// let report = builder.build();
+///
+/// assert_eq!(report.regions()[0].key(), &["FR", "SI"]);
+/// assert_eq!(report.regions()[0].win_rate().unwrap().rate(), 0.7);
+/// ```
+///
+/// [`MetaReport`]: struct.MetaReport.html
+#[derive(Debug, Default)]
+pub struct MetaReportBuilder {
+    champions: HashSet<Card>,
+    decks: usize,
+    cards: HashMap<Card, Tally>,
+    regions: HashMap<Vec<String>, Tally>,
+    // decks grouped by their distinct cards, so that champions are only picked out in `build`
+    card_sets: HashMap<Vec<Card>, Tally>,
+}
+
+impl MetaReportBuilder {
+    /// Create a new empty `MetaReportBuilder`.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the cards that are counted as champions.
+    ///
+    /// The set applies to every ingested deck, including those added before the call.
+    pub fn champions<I: IntoIterator<Item = Card>>(&mut self, champions: I) -> &mut Self {
+        self.champions = champions.into_iter().collect();
+        self
+    }
+
+    /// Ingest a deck without a known result.
+    pub fn add(&mut self, deck: &Deck) -> &mut Self {
+        self.add_with_record(deck, 0, 0)
+    }
+
+    /// Ingest a deck together with the games it won and lost.
+    pub fn add_with_record(&mut self, deck: &Deck, wins: u32, losses: u32) -> &mut Self {
+        let counts = deck.counts();
+        self.decks += 1;
+
+        for (&card, &count) in &counts {
+            self.cards
+                .entry(card.clone())
+                .or_default()
+                .add(count, wins, losses);
+        }
+
+        let regions: Vec<String> = deck.region_counts().into_keys().collect();
+        self.regions
+            .entry(regions)
+            .or_default()
+            .add(0, wins, losses);
+
+        let mut cards: Vec<Card> = counts.into_keys().cloned().collect();
+        cards.sort();
+        self.card_sets
+            .entry(cards)
+            .or_default()
+            .add(0, wins, losses);
+
+        self
+    }
+
+    /// Produce the report for all the decks ingested so far.
+    pub fn build(&self) -> MetaReport {
+        let total = self.decks.max(1) as f64;
+
+        let mut cards: Vec<CardStats> = self
+            .cards
+            .iter()
+            .map(|(card, tally)| CardStats {
+                card: card.to_string(),
+                decks: tally.decks,
+                play_rate: tally.decks as f64 / total,
+                average_copies: tally.copies as f64 / tally.decks as f64,
+                win_rate: WinRate::new(tally.wins, tally.losses),
+            })
+            .collect();
+        cards.sort_by(|a, b| b.decks.cmp(&a.decks).then_with(|| a.card.cmp(&b.card)));
+
+        let mut champion_combos: HashMap<Vec<String>, Tally> = HashMap::new();
+        for (cards, tally) in &self.card_sets {
+            let mut champions: Vec<String> = cards
+                .iter()
+                .filter(|card| self.champions.contains(card))
+                .map(|card| card.to_string())
+                .collect();
+            champions.sort();
+            champion_combos.entry(champions).or_default().merge(tally);
+        }
+
+        MetaReport {
+            decks: self.decks,
+            cards,
+            regions: group_stats(&self.regions, total),
+            champions: group_stats(&champion_combos, total),
+        }
+    }
+}
+
+fn group_stats(groups: &HashMap<Vec<String>, Tally>, total: f64) -> Vec<GroupStats> {
+    let mut stats: Vec<GroupStats> = groups
+        .iter()
+        .map(|(key, tally)| GroupStats {
+            key: key.clone(),
+            decks: tally.decks,
+            play_rate: tally.decks as f64 / total,
+            win_rate: WinRate::new(tally.wins, tally.losses),
+        })
+        .collect();
+    stats.sort_by(|a, b| b.decks.cmp(&a.decks).then_with(|| a.key.cmp(&b.key)));
+
+    stats
+}
@@ -0,0 +1,213 @@
+use std::collections::BTreeMap;
+
+use pyo3::create_exception;
+use pyo3::exceptions::{PyKeyError, PyOverflowError, PyValueError};
+use pyo3::prelude::*;
+
+use crate::card::{Card, CardCodeAndCount};
+use crate::deck::Deck;
+use crate::encoder;
+use crate::error::LorError;
+
+create_exception!(
+    lordeckcodes,
+    DeckCodeError,
+    PyValueError,
+    "Raised when a deck code, card code or count is invalid."
+);
+
+impl From<LorError> for PyErr {
+    fn from(error: LorError) -> Self {
+        DeckCodeError::new_err(error.to_string())
+    }
+}
+
+fn to_tuples(cards: &[CardCodeAndCount]) -> Vec<(String, i32)> {
+    cards
+        .iter()
+        .map(|card_count| (card_count.card().to_string(), card_count.count()))
+        .collect()
+}
+
+fn from_tuples(cards: &[(String, i32)]) -> Result<Vec<CardCodeAndCount>, LorError> {
+    cards
+        .iter()
+        .map(|(code, count)| CardCodeAndCount::from_data(code, *count))
+        .collect()
+}
+
+fn parse_card(code: &str) -> Result<Card, LorError> {
+    Ok(CardCodeAndCount::from_data(code, 1)?.card().clone())
+}
+
+/// Decode a deck code into a list of `(card_code, count)` tuples.
+#[pyfunction]
+fn decode(code: &str) -> PyResult<Vec<(String, i32)>> {
+    Ok(to_tuples(encoder::deck_from_code(code)?.cards()))
+}
+
+/// Encode a list of `(card_code, count)` tuples into a deck code.
+#[pyfunction]
+fn encode(cards: Vec<(String, i32)>) -> PyResult<String> {
+    Ok(encoder::code_from_deck(&Deck::from_vec(from_tuples(
+        &cards,
+    )?))?)
+}
+
+/// Decode many deck codes without holding the GIL, with `None` for invalid codes.
+#[pyfunction]
+fn decode_batch(py: Python<'_>, codes: Vec<String>) -> Vec<Option<Vec<(String, i32)>>> {
+    py.detach(|| {
+        codes
+            .iter()
+            .map(|code| {
+                encoder::deck_from_code(code)
+                    .ok()
+                    .map(|deck| to_tuples(deck.cards()))
+            })
+            .collect()
+    })
+}
+
+/// Encode many decks without holding the GIL, with `None` for invalid decks.
+#[pyfunction]
+fn encode_batch(py: Python<'_>, decks: Vec<Vec<(String, i32)>>) -> Vec<Option<String>> {
+    py.detach(|| {
+        decks
+            .iter()
+            .map(|cards| {
+                from_tuples(cards)
+                    .and_then(|cards| encoder::code_from_deck(&Deck::from_vec(cards)))
+                    .ok()
+            })
+            .collect()
+    })
+}
+
+/// A deck that can be edited card by card.
+///
+/// Each card appears once, with the sum of the copies added.
+#[pyclass(name = "Deck", module = "lordeckcodes")]
+#[derive(Debug, Default)]
+struct PyDeck(Deck);
+
+#[pymethods]
+impl PyDeck {
+    #[new]
+    #[pyo3(signature = (cards = None))]
+    fn new(cards: Option<Vec<(String, i32)>>) -> PyResult<Self> {
+        let mut deck = PyDeck::default();
+        for (code, count) in cards.unwrap_or_default() {
+            deck.add(&code, count)?;
+        }
+        Ok(deck)
+    }
+
+    /// Create a deck from a deck code.
+    #[staticmethod]
+    fn from_code(code: &str) -> PyResult<Self> {
+        Ok(PyDeck(encoder::deck_from_code(code)?))
+    }
+
+    /// Generate the deck code.
+    fn to_code(&self) -> PyResult<String> {
+        Ok(encoder::code_from_deck(&self.0)?)
+    }
+
+    /// Add `count` copies of a card.
+    ///
+    /// Raises `ValueError` if `count` is less than one.
+    #[pyo3(signature = (code, count = 1))]
+    fn add(&mut self, code: &str, count: i32) -> PyResult<()> {
+        let card_count = CardCodeAndCount::from_data(code, count)?;
+        let card = card_count.card().clone();
+        let total = self
+            .0
+            .count(&card)
+            .checked_add(count)
+            .ok_or_else(|| PyValueError::new_err("too many copies"))?;
+        self.0.set_count(card, total);
+        Ok(())
+    }
+
+    /// Remove `count` copies of a card, or all of them if `count` is `None`.
+    ///
+    /// Raises `KeyError` if the deck does not include the card, and `ValueError` if `count`
+    /// is less than one.
+    #[pyo3(signature = (code, count = None))]
+    fn remove(&mut self, code: &str, count: Option<i32>) -> PyResult<()> {
+        let card = parse_card(code)?;
+        if count.is_some_and(|count| count < 1) {
+            return Err(PyValueError::new_err("count must be at least 1"));
+        }
+
+        let total = self.0.count(&card);
+        if total == 0 {
+            return Err(PyKeyError::new_err(code.to_string()));
+        }
+        if count.is_some_and(|count| count > total) {
+            return Err(PyValueError::new_err("not enough copies"));
+        }
+
+        self.0
+            .set_count(card, count.map_or(0, |count| total - count));
+        Ok(())
+    }
+
+    /// Number of copies of a card in the deck.
+    fn count(&self, code: &str) -> PyResult<i32> {
+        Ok(self.0.count(&parse_card(code)?))
+    }
+
+    /// The cards as a list of `(card_code, count)` tuples.
+    #[getter]
+    fn cards(&self) -> Vec<(String, i32)> {
+        to_tuples(self.0.cards())
+    }
+
+    /// Sorted faction identifiers of the cards, such as `["FR", "SI"]`.
+    #[getter]
+    fn regions(&self) -> Vec<String> {
+        self.0.region_counts().into_keys().collect()
+    }
+
+    /// Number of copies of the cards of each faction.
+    #[getter]
+    fn region_counts(&self) -> BTreeMap<String, i32> {
+        self.0.region_counts()
+    }
+
+    fn __len__(&self) -> PyResult<usize> {
+        let total: i64 = self
+            .0
+            .cards()
+            .iter()
+            .map(|card_count| i64::from(card_count.count()))
+            .sum();
+        usize::try_from(total).map_err(|_| PyOverflowError::new_err("deck too large"))
+    }
+
+    fn __contains__(&self, code: &str) -> bool {
+        parse_card(code).is_ok_and(|card| self.0.count(&card) > 0)
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.0.counts() == other.0.counts()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Deck({:?})", self.cards())
+    }
+}
+
+/// Legends of Runeterra deck encoder and decoder.
+#[pymodule]
+pub fn lordeckcodes(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("DeckCodeError", m.py().get_type::<DeckCodeError>())?;
+    m.add_class::<PyDeck>()?;
+    m.add_function(wrap_pyfunction!(decode, m)?)?;
+    m.add_function(wrap_pyfunction!(encode, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_batch, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_batch, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use qrcode::bits::Bits;
+use qrcode::render::svg;
+use qrcode::types::{Color, QrError};
+use qrcode::{EcLevel, QrCode, Version};
+use thiserror::Error;
+
+/// Error correction level used for deck code QR codes.
+const EC_LEVEL: EcLevel = EcLevel::M;
+/// Width of the blank border around the symbol, in modules.
+const QUIET_ZONE: usize = 4;
+/// Largest width and height of a rendered PNG image, in pixels.
+pub const MAX_PNG_SIDE: usize = 8192;
+
+/// An error while building or rendering a QR code.
+#[derive(Debug, Error)]
+pub enum QrCodeError {
+    /// The deck code cannot be stored in a QR code.
+    #[error("qr code encode")]
+    Encode(#[from] QrError),
+    /// The PNG image could not be written.
+    #[error("png encode")]
+    Png(#[from] png::EncodingError),
+    /// The PNG image would be wider than [`MAX_PNG_SIDE`] pixels.
+    ///
+    /// [`MAX_PNG_SIDE`]: constant.MAX_PNG_SIDE.html
+    #[error("png image too large")]
+    ImageTooLarge,
+}
+
+/// Builds the QR code of a deck code.
+///
+/// Deck codes only use the uppercase base32 alphabet, which is a subset of the QR
+/// alphanumeric character set, so the code is stored in alphanumeric mode using the
+/// smallest version that fits. Lowercase codes are uppercased first, and codes with
+/// characters outside the base32 alphabet are rejected.
+pub fn qr_code(code: &str) -> Result<QrCode, QrCodeError> {
+    let data = code.to_ascii_uppercase();
+    if !data
+        .bytes()
+        .all(|c| c.is_ascii_uppercase() || (b'2'..=b'7').contains(&c))
+    {
+        return Err(QrError::InvalidCharacter.into());
+    }
+
+    for version in 1..=40 {
+        let mut bits = Bits::new(Version::Normal(version));
+        let pushed = bits
+            .push_alphanumeric_data(data.as_bytes())
+            .and_then(|_| bits.push_terminator(EC_LEVEL));
+
+        match pushed {
+            Ok(()) => return Ok(QrCode::with_bits(bits, EC_LEVEL)?),
+            Err(QrError::DataTooLong) => continue,
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    Err(QrError::DataTooLong.into())
+}
+
+/// Renders the QR code of a deck code as an SVG document.
+///
+/// `module_size` is the size in pixels of a single module of the symbol.
+///
+/// # Examples
+/// ```
+/// use lordeckcodes::qr;
+///
+/// let svg = qr::to_svg("CEBAIAIFB4WDANQIAEAQGDAUDAQSIJZUAIAQCAIEAEAQKBIA", 4).unwrap();
+/// assert!(svg.contains("<svg"));
+/// ```
+pub fn to_svg(code: &str, module_size: u32) -> Result<String, QrCodeError> {
+    let qr = qr_code(code)?;
+
+    Ok(qr
+        .render::<svg::Color>()
+        .quiet_zone(true)
+        .module_dimensions(module_size, module_size)
+        .build())
+}
+
+/// Renders the QR code of a deck code as a grayscale PNG image.
+///
+/// `module_size` is the size in pixels of a single module of the symbol. Fails with
+/// [`QrCodeError::ImageTooLarge`] if the image would exceed [`MAX_PNG_SIDE`] pixels a side.
+///
+/// [`QrCodeError::ImageTooLarge`]: enum.QrCodeError.html#variant.ImageTooLarge
+/// [`MAX_PNG_SIDE`]: constant.MAX_PNG_SIDE.html
+pub fn to_png(code: &str, module_size: u32) -> Result<Vec<u8>, QrCodeError> {
+    let qr = qr_code(code)?;
+    let module_size = module_size.max(1) as usize;
+    let modules = qr.width();
+    let side = (modules + QUIET_ZONE * 2)
+        .checked_mul(module_size)
+        .filter(|&side| side <= MAX_PNG_SIDE)
+        .ok_or(QrCodeError::ImageTooLarge)?;
+    let colors = qr.to_colors();
+
+    let mut pixels = vec![0xFF; side * side];
+    for y in 0..modules {
+        for x in 0..modules {
+            if colors[y * modules + x] != Color::Dark {
+                continue;
+            }
+
+            for py in 0..module_size {
+                let row = ((y + QUIET_ZONE) * module_size + py) * side;
+                let start = row + (x + QUIET_ZONE) * module_size;
+                pixels[start..start + module_size].fill(0x00);
+            }
+        }
+    }
+
+    let mut png = vec![];
+    let mut encoder = png::Encoder::new(&mut png, side as u32, side as u32);
+    encoder.set_color(png::ColorType::Grayscale);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&pixels)?;
+    writer.finish()?;
+
+    Ok(png)
+}
@@ -0,0 +1,186 @@
+use std::fmt::Write;
+
+use crate::card::Card;
+use crate::database::CardDatabase;
+use crate::deck::Deck;
+use crate::export::{escape_markup, regions, rows, Row};
+
+const WIDTH: u32 = 360;
+const PADDING: u32 = 12;
+const HEADER_HEIGHT: u32 = 56;
+const CURVE_HEIGHT: u32 = 96;
+const ROW_HEIGHT: u32 = 28;
+/// Costs at or above this value share the last bar of the mana curve.
+const CURVE_BUCKETS: usize = 8;
+
+const BACKGROUND: &str = "#1b1e23";
+const TEXT: &str = "#f0e6d2";
+const MUTED: &str = "#8c8577";
+const MANA: &str = "#2f78c4";
+
+fn region_color(card: &Card) -> &'static str {
+    match card.faction_code() {
+        Some("DE") => "#d9cfa6",
+        Some("FR") => "#6fc2e0",
+        Some("IO") => "#d68fb0",
+        Some("NX") => "#b5332e",
+        Some("PZ") => "#e59a40",
+        Some("SI") => "#3ba58a",
+        Some("BW") => "#a85a37",
+        Some("SH") => "#e0c356",
+        Some("MT") => "#8b8fd4",
+        Some("BC") => "#c9d45c",
+        Some("RU") => "#c7b27c",
+        _ => MUTED,
+    }
+}
+
+/// Renders an SVG image of the deck.
+///
+/// The image shows an icon for each region, a bar chart of the mana curve and a row per
+/// card with its cost, name and count. Names and costs come from `database`; unknown cards
+/// are shown with their card code and left out of the mana curve.
+///
+/// # Examples
+/// ```
+/// use lordeckcodes::database::{CardDatabase, CardInfo};
+/// use lordeckcodes::{render, Deck};
+///
+/// let mut database = CardDatabase::new();
+/// database.insert(CardInfo::new("01SI053", "Elise", 2, true).unwrap());
+///
+/// let deck: Deck = [("01SI053", 3)].iter().collect();
+/// let svg = render::svg(&deck, &database);
+/// assert!(svg.starts_with("<svg"));
+/// assert!(svg.contains("Elise"));
+/// ```
+pub fn svg(deck: &Deck, database: &CardDatabase) -> String {
+    let rows = rows(deck, Some(database));
+    let regions = regions(&rows);
+    let height = HEADER_HEIGHT + CURVE_HEIGHT + PADDING * 2 + ROW_HEIGHT * rows.len() as u32;
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{WIDTH}\" height=\"{height}\" \
+         viewBox=\"0 0 {WIDTH} {height}\" font-family=\"sans-serif\">"
+    );
+    let _ = writeln!(
+        svg,
+        "  <rect width=\"{WIDTH}\" height=\"{height}\" fill=\"{BACKGROUND}\"/>"
+    );
+
+    write_regions(&mut svg, &regions);
+    write_curve(&mut svg, &rows);
+
+    let mut y = HEADER_HEIGHT + CURVE_HEIGHT + PADDING;
+    for row in &rows {
+        write_row(&mut svg, row, y);
+        y += ROW_HEIGHT;
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn write_regions(svg: &mut String, regions: &[&[Row]]) {
+    for (i, region) in regions.iter().enumerate() {
+        let card = region[0].card;
+        let cx = PADDING + 20 + i as u32 * 48;
+        let label = card
+            .faction_code()
+            .map_or_else(|| card.faction().to_string(), str::to_string);
+
+        let _ = writeln!(
+            svg,
+            "  <g class=\"region\"><title>{}</title>\
+             <circle cx=\"{cx}\" cy=\"28\" r=\"18\" fill=\"{}\"/>\
+             <text x=\"{cx}\" y=\"33\" font-size=\"13\" font-weight=\"bold\" \
+             text-anchor=\"middle\" fill=\"{BACKGROUND}\">{}</text></g>",
+            escape_markup(&region[0].region()),
+            region_color(card),
+            label
+        );
+    }
+}
+
+fn write_curve(svg: &mut String, rows: &[Row]) {
+    let mut buckets = [0i64; CURVE_BUCKETS];
+    for row in rows {
+        if let Some(cost) = row.cost {
+            buckets[(cost as usize).min(CURVE_BUCKETS - 1)] += i64::from(row.count);
+        }
+    }
+
+    let max = buckets.iter().copied().max().unwrap_or(0).max(1);
+    let bar_width = (WIDTH - PADDING * 2) / CURVE_BUCKETS as u32;
+    let base = HEADER_HEIGHT + CURVE_HEIGHT - 16;
+    let max_bar = CURVE_HEIGHT - 36;
+
+    let _ = writeln!(svg, "  <g class=\"curve\">");
+    for (cost, &count) in buckets.iter().enumerate() {
+        let x = PADDING + cost as u32 * bar_width;
+        let bar = (i64::from(max_bar) * count.max(0) / max) as u32;
+        let label = if cost == CURVE_BUCKETS - 1 {
+            format!("{cost}+")
+        } else {
+            cost.to_string()
+        };
+
+        let _ = writeln!(
+            svg,
+            "    <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{bar}\" fill=\"{MANA}\"/>",
+            x + 4,
+            base - bar,
+            bar_width - 8
+        );
+        if count > 0 {
+            let _ = writeln!(
+                svg,
+                "    <text x=\"{}\" y=\"{}\" font-size=\"11\" text-anchor=\"middle\" \
+                 fill=\"{TEXT}\">{count}</text>",
+                x + bar_width / 2,
+                base - bar - 4
+            );
+        }
+        let _ = writeln!(
+            svg,
+            "    <text x=\"{}\" y=\"{}\" font-size=\"11\" text-anchor=\"middle\" \
+             fill=\"{MUTED}\">{label}</text>",
+            x + bar_width / 2,
+            base + 13
+        );
+    }
+    let _ = writeln!(svg, "  </g>");
+}
+
+fn write_row(svg: &mut String, row: &Row, y: u32) {
+    let cost = row.cost.map(|cost| cost.to_string()).unwrap_or_default();
+
+    let _ = writeln!(
+        svg,
+        "  <g class=\"card\" data-code=\"{}\">\
+         <rect x=\"{PADDING}\" y=\"{y}\" width=\"{}\" height=\"{}\" rx=\"4\" fill=\"{}\" \
+         fill-opacity=\"0.25\"/>\
+         <circle cx=\"{}\" cy=\"{}\" r=\"10\" fill=\"{MANA}\"/>\
+         <text x=\"{}\" y=\"{}\" font-size=\"12\" font-weight=\"bold\" text-anchor=\"middle\" \
+         fill=\"{TEXT}\">{cost}</text>\
+         <text x=\"{}\" y=\"{}\" font-size=\"13\" fill=\"{TEXT}\">{}</text>\
+         <text x=\"{}\" y=\"{}\" font-size=\"13\" text-anchor=\"end\" \
+         fill=\"{TEXT}\">x{}</text></g>",
+        row.card,
+        WIDTH - PADDING * 2,
+        ROW_HEIGHT - 4,
+        region_color(row.card),
+        PADDING + 16,
+        y + 12,
+        PADDING + 16,
+        y + 16,
+        PADDING + 34,
+        y + 17,
+        escape_markup(&row.name),
+        WIDTH - PADDING - 10,
+        y + 17,
+        row.count
+    );
+}
@@ -0,0 +1,181 @@
+use alloc::string::String;
+use core::fmt;
+
+use ::serde::de::{self, MapAccess, Visitor};
+use ::serde::ser::{self, SerializeMap};
+use ::serde::{Deserializer, Serializer};
+
+use crate::card::{Card, CardCodeAndCount};
+use crate::deck::Deck;
+use crate::encoder;
+use crate::error::LorError;
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for crate::Card {}
+    impl Sealed for crate::Deck {}
+}
+
+/// Types with a string code: a [`Deck`] and its deck code, or a [`Card`] and its card
+/// code such as `"01SI015"`.
+///
+/// [`Deck`]: ../struct.Deck.html
+/// [`Card`]: ../struct.Card.html
+pub trait Code: private::Sealed + Sized {
+    /// Generate the code.
+    fn to_code(&self) -> Result<String, LorError>;
+
+    /// Parse a code, checking it is valid.
+    fn from_code(code: &str) -> Result<Self, LorError>;
+}
+
+impl Code for Deck {
+    fn to_code(&self) -> Result<String, LorError> {
+        encoder::code_from_deck(self)
+    }
+
+    fn from_code(code: &str) -> Result<Self, LorError> {
+        encoder::deck_from_code(code)
+    }
+}
+
+impl Code for Card {
+    /// Fails with [`LorError::InvalidCard`] for cards of factions unknown to this version of
+    /// the library, which have no card code.
+    ///
+    /// [`LorError::InvalidCard`]: ../enum.LorError.html#variant.InvalidCard
+    fn to_code(&self) -> Result<String, LorError> {
+        self.code()
+    }
+
+    fn from_code(code: &str) -> Result<Self, LorError> {
+        Ok(CardCodeAndCount::from_data(code, 1)?.card().clone())
+    }
+}
+
+/// Serializes a [`Deck`] as its deck code and a [`Card`] as its card code.
+///
+/// # Examples
+/// ```
+/// use lordeckcodes::{Card, Deck};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Submission {
+///     #[serde(with = "lordeckcodes::serde::as_code")]
+///     deck: Deck,
+///     #[serde(with = "lordeckcodes::serde::as_code")]
+///     featured: Card,
+///     #[serde(with = "lordeckcodes::serde::as_map")]
+///     sideboard: Deck,
+/// }
+///
+/// let json = r#"{
+///     "deck": "CEBAIAIFB4WDANQIAEAQGDAUDAQSIJZUAIAQCAIEAEAQKBIA",
+///     "featured": "01SI015",
+///     "sideboard": {"01SI015": 1, "01FR003": 2}
+/// }"#;
+/// let submission: Submission = serde_json::from_str(json).unwrap();
+/// assert_eq!(submission.deck.cards().len(), 14);
+/// assert_eq!(submission.featured.to_string(), "01SI015");
+/// assert_eq!(submission.sideboard.cards()[1].count(), 2);
+/// ```
+///
+/// [`Deck`]: ../../struct.Deck.html
+/// [`Card`]: ../../struct.Card.html
+pub mod as_code {
+    use super::*;
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Code,
+        S: Serializer,
+    {
+        let code = value.to_code().map_err(ser::Error::custom)?;
+        serializer.serialize_str(&code)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: Code,
+        D: Deserializer<'de>,
+    {
+        struct CodeVisitor<T>(core::marker::PhantomData<T>);
+
+        impl<T: Code> Visitor<'_> for CodeVisitor<T> {
+            type Value = T;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a deck or card code")
+            }
+
+            fn visit_str<E: de::Error>(self, code: &str) -> Result<T, E> {
+                T::from_code(code).map_err(|e| E::custom(format_args!("{e}: {code}")))
+            }
+        }
+
+        deserializer.deserialize_str(CodeVisitor(core::marker::PhantomData))
+    }
+}
+
+/// Serializes a [`Deck`] as a map of card codes to counts, such as `{"01SI015": 3}`.
+///
+/// Entries referring to the same card are merged. Serializing fails for cards without a card
+/// code, or if the merged count of a card does not fit in an `i32`.
+///
+/// [`Deck`]: ../../struct.Deck.html
+pub mod as_map {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(deck: &Deck, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut entries: alloc::vec::Vec<(&Card, i32)> = alloc::vec![];
+        for card_count in deck.cards() {
+            match entries
+                .iter_mut()
+                .find(|(card, _)| *card == card_count.card())
+            {
+                Some((_, count)) => {
+                    *count = count
+                        .checked_add(card_count.count())
+                        .ok_or_else(|| ser::Error::custom("too many copies"))?;
+                }
+                None => entries.push((card_count.card(), card_count.count())),
+            }
+        }
+
+        let mut map = serializer.serialize_map(Some(entries.len()))?;
+        for (card, count) in entries {
+            let code = card.to_code().map_err(ser::Error::custom)?;
+            map.serialize_entry(&code, &count)?;
+        }
+        map.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Deck, D::Error> {
+        struct MapVisitor;
+
+        impl<'de> Visitor<'de> for MapVisitor {
+            type Value = Deck;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a map of card codes to counts")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Deck, A::Error> {
+                let mut deck = Deck::new();
+                while let Some((code, count)) = map.next_entry::<String, i32>()? {
+                    let card_count = CardCodeAndCount::from_data(&code, count)
+                        .map_err(|e| de::Error::custom(format_args!("{e}: {code}")))?;
+                    if deck.cards().iter().any(|c| c.card() == card_count.card()) {
+                        return Err(de::Error::custom(format_args!("duplicate card: {code}")));
+                    }
+                    deck.add(card_count);
+                }
+                Ok(deck)
+            }
+        }
+
+        deserializer.deserialize_map(MapVisitor)
+    }
+}
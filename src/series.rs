@@ -0,0 +1,347 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use crate::deck::Deck;
+use crate::lineup::Lineup;
+
+/// Win rates of archetypes against each other.
+///
+/// Matchups that were not provided are considered even, and mirror matchups are always even.
+///
+/// # Examples
+/// ```
+/// use lordeckcodes::series::MatchupTable;
+///
+/// let mut table = MatchupTable::new();
+/// table.insert("Elise", "Anivia", 0.6);
+///
+/// assert_eq!(table.win_rate("Elise", "Anivia"), 0.6);
+/// assert_eq!(table.win_rate("Anivia", "Elise"), 0.4);
+/// assert_eq!(table.win_rate("Elise", "Ezreal"), 0.5);
+/// assert_eq!(table.win_rate("Elise", "Elise"), 0.5);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MatchupTable {
+    win_rates: HashMap<(String, String), f64>,
+}
+
+impl MatchupTable {
+    /// Create a new empty `MatchupTable`.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the win rate of `archetype` against `opponent`, and the mirrored win rate.
+    ///
+    /// Does nothing if `archetype` and `opponent` are the same, since mirrors are even.
+    pub fn insert(&mut self, archetype: &str, opponent: &str, win_rate: f64) {
+        if archetype == opponent {
+            return;
+        }
+
+        let win_rate = win_rate.clamp(0.0, 1.0);
+        self.win_rates
+            .insert((archetype.to_string(), opponent.to_string()), win_rate);
+        self.win_rates.insert(
+            (opponent.to_string(), archetype.to_string()),
+            1.0 - win_rate,
+        );
+    }
+
+    /// Probability of `archetype` winning a game against `opponent`.
+    pub fn win_rate(&self, archetype: &str, opponent: &str) -> f64 {
+        self.win_rates
+            .get(&(archetype.to_string(), opponent.to_string()))
+            .copied()
+            .unwrap_or(0.5)
+    }
+}
+
+/// How a series between two lineups is played.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeriesFormat {
+    /// A player must win a game with each of their decks. Decks are picked blindly for
+    /// every game, which is modelled as an even choice among the decks that still need a win.
+    Conquest,
+    /// A deck that loses is eliminated and the winning deck must be played again. The
+    /// first decks are picked blindly, after which the loser picks their best counter.
+    LastDeckStanding,
+}
+
+/// Outcome of the ban phase computed by [`analyze_bans`].
+///
+/// Bans are indices of the banned deck in the opposing lineup.
+///
+/// [`analyze_bans`]: fn.analyze_bans.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct BanAnalysis {
+    ban: usize,
+    opponent_ban: usize,
+    win_probability: f64,
+    matrix: Vec<Vec<f64>>,
+}
+
+impl BanAnalysis {
+    /// The opponent deck the player should ban, maximizing their worst-case win probability.
+    pub fn ban(&self) -> usize {
+        self.ban
+    }
+
+    /// The player deck the opponent should ban, minimizing the player's best-case win
+    /// probability.
+    pub fn opponent_ban(&self) -> usize {
+        self.opponent_ban
+    }
+
+    /// Series win probability of the player when both sides make the recommended bans.
+    pub fn win_probability(&self) -> f64 {
+        self.win_probability
+    }
+
+    /// Series win probability of the player, indexed by the player's ban then the opponent's.
+    pub fn matrix(&self) -> &[Vec<f64>] {
+        &self.matrix
+    }
+}
+
+/// Maximum number of decks in a lineup for [`series_win_probability`] and [`analyze_bans`].
+///
+/// [`series_win_probability`]: fn.series_win_probability.html
+/// [`analyze_bans`]: fn.analyze_bans.html
+pub const MAX_SERIES_DECKS: usize = 6;
+
+/// Probability of the player winning a series between the given archetypes.
+///
+/// A player without any deck loses the series. Returns `None` if a lineup has more than
+/// [`MAX_SERIES_DECKS`] decks.
+///
+/// # Examples
+/// ```
+/// use lordeckcodes::series::{series_win_probability, MatchupTable, SeriesFormat};
+///
+/// let mut table = MatchupTable::new();
+/// table.insert("A", "X", 0.6);
+///
+/// let probability = series_win_probability(SeriesFormat::Conquest, &["A"], &["X"], &table);
+/// assert!((probability.unwrap() - 0.6).abs() < 1e-9);
+/// ```
+///
+/// [`MAX_SERIES_DECKS`]: constant.MAX_SERIES_DECKS.html
+pub fn series_win_probability<S: AsRef<str>>(
+    format: SeriesFormat,
+    player: &[S],
+    opponent: &[S],
+    table: &MatchupTable,
+) -> Option<f64> {
+    if player.len() > MAX_SERIES_DECKS || opponent.len() > MAX_SERIES_DECKS {
+        return None;
+    }
+    if player.is_empty() {
+        return Some(0.0);
+    }
+    if opponent.is_empty() {
+        return Some(1.0);
+    }
+
+    let player: Vec<&str> = player.iter().map(AsRef::as_ref).collect();
+    let opponent: Vec<&str> = opponent.iter().map(AsRef::as_ref).collect();
+    let series = Series {
+        player: &player,
+        opponent: &opponent,
+        table,
+        conquest: RefCell::default(),
+        last_deck_standing: RefCell::default(),
+    };
+
+    let all_player = (1u32 << player.len()) - 1;
+    let all_opponent = (1u32 << opponent.len()) - 1;
+    let probability = match format {
+        SeriesFormat::Conquest => series.conquest(all_player, all_opponent),
+        SeriesFormat::LastDeckStanding => series.blind_pick(all_player, all_opponent, |a, b| {
+            series.last_deck_standing_game(all_player, all_opponent, a, b)
+        }),
+    };
+
+    Some(probability)
+}
+
+/// Computes the bans of two lineups and the resulting series win probability.
+///
+/// Each player bans one deck of the opposing lineup without knowing the other's ban.
+/// `archetype` gives the archetype of a deck, as used in `table`. Returns `None` if a
+/// lineup has fewer than two decks or more than [`MAX_SERIES_DECKS`].
+///
+/// # Examples
+/// ```
+/// use lordeckcodes::lineup::Lineup;
+/// use lordeckcodes::series::{analyze_bans, MatchupTable, SeriesFormat};
+/// use lordeckcodes::Deck;
+///
+/// let lineup = |codes: &[&str]| {
+///     Lineup::new(codes.iter().map(|code| [(*code, 3)].iter().collect()).collect())
+/// };
+/// let player = lineup(&["01SI001", "01FR001", "01DE001"]);
+/// let opponent = lineup(&["01IO001", "01NX001", "01PZ001"]);
+///
+/// let mut table = MatchupTable::new();
+/// table.insert("SI", "PZ", 0.1);
+///
+/// let archetype = |deck: &Deck| deck.cards()[0].card().faction_code().unwrap().to_string();
+/// let bans = analyze_bans(SeriesFormat::Conquest, &player, &opponent, &table, archetype);
+/// assert_eq!(bans.unwrap().ban(), 2);
+/// ```
+///
+/// [`MAX_SERIES_DECKS`]: constant.MAX_SERIES_DECKS.html
+pub fn analyze_bans<F>(
+    format: SeriesFormat,
+    player: &Lineup,
+    opponent: &Lineup,
+    table: &MatchupTable,
+    archetype: F,
+) -> Option<BanAnalysis>
+where
+    F: Fn(&Deck) -> String,
+{
+    let sizes = 2..=MAX_SERIES_DECKS;
+    if !sizes.contains(&player.decks().len()) || !sizes.contains(&opponent.decks().len()) {
+        return None;
+    }
+
+    let player: Vec<String> = player.decks().iter().map(&archetype).collect();
+    let opponent: Vec<String> = opponent.decks().iter().map(&archetype).collect();
+    let without = |archetypes: &[String], ban: usize| -> Vec<String> {
+        let mut archetypes = archetypes.to_vec();
+        archetypes.remove(ban);
+        archetypes
+    };
+
+    let matrix: Vec<Vec<f64>> = (0..opponent.len())
+        .map(|ban| {
+            (0..player.len())
+                .map(|opponent_ban| {
+                    series_win_probability(
+                        format,
+                        &without(&player, opponent_ban),
+                        &without(&opponent, ban),
+                        table,
+                    )
+                })
+                .collect::<Option<_>>()
+        })
+        .collect::<Option<_>>()?;
+
+    let worst = |row: &Vec<f64>| row.iter().copied().fold(f64::INFINITY, f64::min);
+    let ban = (0..matrix.len())
+        .max_by(|&a, &b| {
+            worst(&matrix[a])
+                .total_cmp(&worst(&matrix[b]))
+                .then(b.cmp(&a))
+        })
+        .unwrap();
+
+    let best = |column: usize| {
+        matrix
+            .iter()
+            .map(|row| row[column])
+            .fold(f64::NEG_INFINITY, f64::max)
+    };
+    let opponent_ban = (0..player.len())
+        .min_by(|&a, &b| best(a).total_cmp(&best(b)).then(a.cmp(&b)))
+        .unwrap();
+
+    Some(BanAnalysis {
+        ban,
+        opponent_ban,
+        win_probability: matrix[ban][opponent_ban],
+        matrix,
+    })
+}
+
+/// Archetypes of the decks in a series, with remaining decks tracked as bit sets.
+///
+/// Results are cached by the remaining decks, and by the decks in play for last deck
+/// standing.
+struct Series<'a> {
+    player: &'a [&'a str],
+    opponent: &'a [&'a str],
+    table: &'a MatchupTable,
+    conquest: RefCell<HashMap<(u32, u32), f64>>,
+    last_deck_standing: RefCell<HashMap<(u32, u32, usize, usize), f64>>,
+}
+
+impl Series<'_> {
+    fn win_rate(&self, a: usize, b: usize) -> f64 {
+        self.table.win_rate(self.player[a], self.opponent[b])
+    }
+
+    /// Average of `game` over every pair of decks the players may pick.
+    fn blind_pick<F: Fn(usize, usize) -> f64>(&self, player: u32, opponent: u32, game: F) -> f64 {
+        let pairs: Vec<(usize, usize)> = decks(player)
+            .flat_map(|a| decks(opponent).map(move |b| (a, b)))
+            .collect();
+
+        pairs.iter().map(|&(a, b)| game(a, b)).sum::<f64>() / pairs.len() as f64
+    }
+
+    fn conquest(&self, player: u32, opponent: u32) -> f64 {
+        if player == 0 {
+            return 1.0;
+        }
+        if opponent == 0 {
+            return 0.0;
+        }
+        if let Some(&probability) = self.conquest.borrow().get(&(player, opponent)) {
+            return probability;
+        }
+
+        let probability = self.blind_pick(player, opponent, |a, b| {
+            let w = self.win_rate(a, b);
+            w * self.conquest(player & !(1 << a), opponent)
+                + (1.0 - w) * self.conquest(player, opponent & !(1 << b))
+        });
+        self.conquest
+            .borrow_mut()
+            .insert((player, opponent), probability);
+
+        probability
+    }
+
+    fn last_deck_standing_game(&self, player: u32, opponent: u32, a: usize, b: usize) -> f64 {
+        let key = (player, opponent, a, b);
+        if let Some(&probability) = self.last_deck_standing.borrow().get(&key) {
+            return probability;
+        }
+
+        let w = self.win_rate(a, b);
+
+        // the loser's deck is eliminated and the loser picks the best deck against the winner
+        let remaining_opponent = opponent & !(1 << b);
+        let won = if remaining_opponent == 0 {
+            1.0
+        } else {
+            decks(remaining_opponent)
+                .map(|next| self.last_deck_standing_game(player, remaining_opponent, a, next))
+                .fold(f64::INFINITY, f64::min)
+        };
+
+        let remaining_player = player & !(1 << a);
+        let lost = if remaining_player == 0 {
+            0.0
+        } else {
+            decks(remaining_player)
+                .map(|next| self.last_deck_standing_game(remaining_player, opponent, next, b))
+                .fold(f64::NEG_INFINITY, f64::max)
+        };
+
+        let probability = w * won + (1.0 - w) * lost;
+        self.last_deck_standing
+            .borrow_mut()
+            .insert(key, probability);
+
+        probability
+    }
+}
+
+fn decks(set: u32) -> impl Iterator<Item = usize> {
+    (0..u32::BITS as usize).filter(move |&i| set & (1 << i) != 0)
+}
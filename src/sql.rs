@@ -0,0 +1,95 @@
+use crate::card::{Card, CardCodeAndCount};
+use crate::deck::Deck;
+use crate::encoder;
+use crate::error::LorError;
+
+fn card_from_code(code: &str) -> Result<Card, LorError> {
+    Ok(CardCodeAndCount::from_data(code, 1)?.card().clone())
+}
+
+#[cfg(feature = "sqlx")]
+mod sqlx_types {
+    use sqlx::encode::IsNull;
+    use sqlx::error::BoxDynError;
+    use sqlx::{Database, Decode, Encode, Type};
+
+    use super::*;
+
+    macro_rules! impl_sqlx {
+        ($ty:ty, $to_code:expr, $from_code:expr) => {
+            impl<DB: Database> Type<DB> for $ty
+            where
+                String: Type<DB>,
+            {
+                fn type_info() -> DB::TypeInfo {
+                    <String as Type<DB>>::type_info()
+                }
+
+                fn compatible(ty: &DB::TypeInfo) -> bool {
+                    <String as Type<DB>>::compatible(ty)
+                }
+            }
+
+            impl<'q, DB: Database> Encode<'q, DB> for $ty
+            where
+                String: Encode<'q, DB>,
+            {
+                fn encode_by_ref(
+                    &self,
+                    buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+                ) -> Result<IsNull, BoxDynError> {
+                    $to_code(self)?.encode(buf)
+                }
+            }
+
+            impl<'r, DB: Database> Decode<'r, DB> for $ty
+            where
+                &'r str: Decode<'r, DB>,
+            {
+                fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+                    let code = <&str as Decode<DB>>::decode(value)?;
+                    Ok($from_code(code)?)
+                }
+            }
+        };
+    }
+
+    impl_sqlx!(Deck, encoder::code_from_deck, encoder::deck_from_code);
+    impl_sqlx!(Card, Card::code, card_from_code);
+}
+
+#[cfg(feature = "rusqlite")]
+mod rusqlite_types {
+    use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+    use super::*;
+
+    fn to_sql_error(error: LorError) -> rusqlite::Error {
+        rusqlite::Error::ToSqlConversionFailure(Box::new(error))
+    }
+
+    impl ToSql for Deck {
+        fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+            let code = encoder::code_from_deck(self).map_err(to_sql_error)?;
+            Ok(ToSqlOutput::from(code))
+        }
+    }
+
+    impl FromSql for Deck {
+        fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+            encoder::deck_from_code(value.as_str()?).map_err(|e| FromSqlError::Other(Box::new(e)))
+        }
+    }
+
+    impl ToSql for Card {
+        fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+            Ok(ToSqlOutput::from(self.code().map_err(to_sql_error)?))
+        }
+    }
+
+    impl FromSql for Card {
+        fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+            card_from_code(value.as_str()?).map_err(|e| FromSqlError::Other(Box::new(e)))
+        }
+    }
+}
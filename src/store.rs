@@ -0,0 +1,627 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension, Transaction};
+use thiserror::Error;
+
+use crate::card::{Card, CardCodeAndCount};
+use crate::deck::{Deck, DeckDiff};
+use crate::encoder;
+use crate::error::LorError;
+use crate::history::DeckHistory;
+
+const SCHEMA: &str = "
+    PRAGMA foreign_keys = ON;
+    CREATE TABLE IF NOT EXISTS decks (
+        id INTEGER PRIMARY KEY,
+        code TEXT NOT NULL UNIQUE,
+        name TEXT NOT NULL,
+        notes TEXT NOT NULL DEFAULT '',
+        created_at INTEGER NOT NULL,
+        updated_at INTEGER NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS deck_tags (
+        deck_id INTEGER NOT NULL REFERENCES decks (id) ON DELETE CASCADE,
+        tag TEXT NOT NULL,
+        PRIMARY KEY (deck_id, tag)
+    );
+    CREATE TABLE IF NOT EXISTS deck_cards (
+        deck_id INTEGER NOT NULL REFERENCES decks (id) ON DELETE CASCADE,
+        card TEXT NOT NULL,
+        region TEXT NOT NULL,
+        count INTEGER NOT NULL,
+        PRIMARY KEY (deck_id, card)
+    );
+    CREATE INDEX IF NOT EXISTS deck_cards_card ON deck_cards (card);
+    CREATE INDEX IF NOT EXISTS deck_cards_region ON deck_cards (region);
+    CREATE TABLE IF NOT EXISTS deck_versions (
+        deck_id INTEGER NOT NULL REFERENCES decks (id) ON DELETE CASCADE,
+        version INTEGER NOT NULL,
+        code TEXT NOT NULL,
+        saved_at INTEGER NOT NULL,
+        PRIMARY KEY (deck_id, version)
+    );
+    CREATE TABLE IF NOT EXISTS champions (
+        card TEXT PRIMARY KEY
+    );
+";
+
+/// An error of a [`DeckStore`].
+///
+/// [`DeckStore`]: struct.DeckStore.html
+#[derive(Debug, Error)]
+pub enum StoreError {
+    /// A deck code or card code is invalid.
+    #[error("deck code")]
+    Code(#[from] LorError),
+    /// The database could not be read or written.
+    #[error("deck store")]
+    Database(#[from] rusqlite::Error),
+    #[error("deck not found")]
+    DeckNotFound,
+    /// A query used a champion that is not one of the store's champions.
+    #[error("unknown champion {0}")]
+    UnknownChampion(Card),
+}
+
+/// Seconds since the Unix epoch.
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// Identifier of a deck saved in a [`DeckStore`], kept across versions of the deck.
+///
+/// [`DeckStore`]: struct.DeckStore.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DeckId(i64);
+
+impl fmt::Display for DeckId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A deck saved in a [`DeckStore`], at its latest version.
+///
+/// [`DeckStore`]: struct.DeckStore.html
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredDeck {
+    id: DeckId,
+    name: String,
+    notes: String,
+    tags: Vec<String>,
+    code: String,
+    deck: Deck,
+    champions: Vec<Card>,
+    created_at: u64,
+    updated_at: u64,
+}
+
+impl StoredDeck {
+    pub fn id(&self) -> DeckId {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn notes(&self) -> &str {
+        &self.notes
+    }
+
+    /// Tags of the deck, sorted.
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    /// Normalized code of the deck.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    pub fn deck(&self) -> &Deck {
+        &self.deck
+    }
+
+    /// Cards of the deck that the store counts as champions, sorted.
+    pub fn champions(&self) -> &[Card] {
+        &self.champions
+    }
+
+    /// When the deck was first saved, in seconds since the Unix epoch.
+    pub fn created_at(&self) -> u64 {
+        self.created_at
+    }
+
+    /// When the deck was last given a new code, in seconds since the Unix epoch.
+    pub fn updated_at(&self) -> u64 {
+        self.updated_at
+    }
+}
+
+/// A code a stored deck had at some point.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeckVersion {
+    version: u32,
+    code: String,
+    saved_at: u64,
+    changes: DeckDiff,
+}
+
+impl DeckVersion {
+    /// Version number, starting at 1 when the deck is first saved.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Normalized code of the deck at this version.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// When the version was saved, in seconds since the Unix epoch.
+    pub fn saved_at(&self) -> u64 {
+        self.saved_at
+    }
+
+    /// Changes from the previous version, empty for the first one.
+    pub fn changes(&self) -> &DeckDiff {
+        &self.changes
+    }
+}
+
+/// Conditions a deck must meet to be returned by [`DeckStore::search`].
+///
+/// An empty query matches every deck. Conditions are combined, so a deck must meet all of them.
+///
+/// [`DeckStore::search`]: struct.DeckStore.html#method.search
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DeckQuery {
+    cards: Vec<Card>,
+    champions: Vec<Card>,
+    regions: Vec<String>,
+    tags: Vec<String>,
+    name: Option<String>,
+}
+
+impl DeckQuery {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Only decks including `card`.
+    pub fn card(mut self, card: Card) -> Self {
+        self.cards.push(card);
+        self
+    }
+
+    /// Only decks including `champion`, which must be one of the store's champions.
+    pub fn champion(mut self, champion: Card) -> Self {
+        self.champions.push(champion);
+        self
+    }
+
+    /// Only decks with cards from the region with the faction identifier `region` (e.g. `"SI"`).
+    pub fn region(mut self, region: &str) -> Self {
+        self.regions.push(region.to_string());
+        self
+    }
+
+    /// Only decks tagged with `tag`.
+    pub fn tag(mut self, tag: &str) -> Self {
+        self.tags.push(tag.to_string());
+        self
+    }
+
+    /// Only decks whose name contains `name`, ignoring ASCII case.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+}
+
+/// A library of decks saved in a SQLite database.
+///
+/// Decks are keyed by their normalized code, so saving the same deck twice, even from codes
+/// with different card order, keeps a single entry. Giving a deck a new code keeps its name,
+/// notes and tags and records the new code as the next version.
+///
+/// # Examples
+/// ```
+/// use lordeckcodes::store::{DeckQuery, DeckStore, StoreError};
+///
+/// fn main() -> Result<(), StoreError> {
+///     let mut store = DeckStore::open_in_memory()?;
+///     let code = "CEBAEAIBAQTQMAIAAILSQLBNGUBACAIBFYDACAAHBEHR2IBLAEBACAIFAY";
+///     let id = store.save("Demacia Freljord", code)?;
+///     store.add_tag(id, "ladder")?;
+///
+///     let decks = store.search(&DeckQuery::new().region("DE").tag("ladder"))?;
+///     assert_eq!(decks[0].name(), "Demacia Freljord");
+///     Ok(())
+/// }
+/// ```
+pub struct DeckStore {
+    conn: Connection,
+    champions: HashSet<Card>,
+}
+
+impl DeckStore {
+    /// Open the store in the database file at `path`, creating it if needed.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<DeckStore, StoreError> {
+        DeckStore::with_connection(Connection::open(path)?)
+    }
+
+    /// Open a store that only lives in memory.
+    pub fn open_in_memory() -> Result<DeckStore, StoreError> {
+        DeckStore::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(conn: Connection) -> Result<DeckStore, StoreError> {
+        conn.execute_batch(SCHEMA)?;
+        let mut statement = conn.prepare("SELECT card FROM champions")?;
+        let codes = statement
+            .query_map([], |row| row.get(0))?
+            .collect::<Result<Vec<String>, _>>()?;
+        let champions = codes
+            .iter()
+            .map(|code| Ok(CardCodeAndCount::from_data(code, 1)?.card().clone()))
+            .collect::<Result<HashSet<Card>, LorError>>()?;
+        drop(statement);
+
+        Ok(DeckStore { conn, champions })
+    }
+
+    /// The cards that are counted as champions.
+    pub fn champions(&self) -> &HashSet<Card> {
+        &self.champions
+    }
+
+    /// Replace the cards that are counted as champions. They are saved in the database, so
+    /// they are kept when the store is opened again.
+    pub fn set_champions<I: IntoIterator<Item = Card>>(
+        &mut self,
+        champions: I,
+    ) -> Result<(), StoreError> {
+        let champions: HashSet<Card> = champions.into_iter().collect();
+        let tx = self.conn.transaction()?;
+        tx.execute("DELETE FROM champions", [])?;
+        for champion in &champions {
+            tx.execute(
+                "INSERT INTO champions (card) VALUES (?1)",
+                [champion.to_string()],
+            )?;
+        }
+        tx.commit()?;
+
+        self.champions = champions;
+        Ok(())
+    }
+
+    /// Save the deck with the given code under `name`.
+    ///
+    /// If a deck with the same normalized code is already saved, it is left unchanged and its
+    /// identifier is returned.
+    pub fn save(&mut self, name: &str, code: &str) -> Result<DeckId, StoreError> {
+        let code = encoder::normalize_code(code)?;
+        if let Some(id) = self.find_id(&code)? {
+            return Ok(id);
+        }
+
+        let deck = encoder::deck_from_code(&code)?;
+        let saved_at = now();
+        let tx = self.conn.transaction()?;
+        tx.execute(
+            "INSERT INTO decks (code, name, created_at, updated_at) VALUES (?1, ?2, ?3, ?3)",
+            params![code, name, saved_at],
+        )?;
+        let id = DeckId(tx.last_insert_rowid());
+        insert_version(&tx, id, &code, &deck, 1, saved_at)?;
+        tx.commit()?;
+
+        Ok(id)
+    }
+
+    /// Give the deck a new code, recording it as the next version.
+    ///
+    /// Nothing changes if the deck already has this normalized code. Fails if another saved deck
+    /// has it.
+    pub fn update(&mut self, id: DeckId, code: &str) -> Result<(), StoreError> {
+        let code = encoder::normalize_code(code)?;
+        let current: String = self
+            .conn
+            .query_row("SELECT code FROM decks WHERE id = ?1", [id.0], |row| {
+                row.get(0)
+            })
+            .optional()?
+            .ok_or(StoreError::DeckNotFound)?;
+        if current == code {
+            return Ok(());
+        }
+
+        let deck = encoder::deck_from_code(&code)?;
+        let saved_at = now();
+        let tx = self.conn.transaction()?;
+        let version: u32 = tx.query_row(
+            "SELECT MAX(version) FROM deck_versions WHERE deck_id = ?1",
+            [id.0],
+            |row| row.get(0),
+        )?;
+        tx.execute(
+            "UPDATE decks SET code = ?2, updated_at = ?3 WHERE id = ?1",
+            params![id.0, code, saved_at],
+        )?;
+        tx.execute("DELETE FROM deck_cards WHERE deck_id = ?1", [id.0])?;
+        insert_version(&tx, id, &code, &deck, version + 1, saved_at)?;
+        tx.commit()?;
+
+        Ok(())
+    }
+
+    /// Change the name of the deck.
+    pub fn rename(&mut self, id: DeckId, name: &str) -> Result<(), StoreError> {
+        self.update_row("UPDATE decks SET name = ?2 WHERE id = ?1", id, name)
+    }
+
+    /// Replace the notes of the deck.
+    pub fn set_notes(&mut self, id: DeckId, notes: &str) -> Result<(), StoreError> {
+        self.update_row("UPDATE decks SET notes = ?2 WHERE id = ?1", id, notes)
+    }
+
+    /// Tag the deck with `tag`, if it is not already.
+    pub fn add_tag(&mut self, id: DeckId, tag: &str) -> Result<(), StoreError> {
+        self.check_exists(id)?;
+        self.conn.execute(
+            "INSERT OR IGNORE INTO deck_tags (deck_id, tag) VALUES (?1, ?2)",
+            params![id.0, tag],
+        )?;
+        Ok(())
+    }
+
+    /// Remove `tag` from the deck, returning whether the deck had it.
+    pub fn remove_tag(&mut self, id: DeckId, tag: &str) -> Result<bool, StoreError> {
+        self.check_exists(id)?;
+        let removed = self.conn.execute(
+            "DELETE FROM deck_tags WHERE deck_id = ?1 AND tag = ?2",
+            params![id.0, tag],
+        )?;
+        Ok(removed > 0)
+    }
+
+    /// Delete the deck and its history, returning whether it was saved.
+    pub fn delete(&mut self, id: DeckId) -> Result<bool, StoreError> {
+        let deleted = self
+            .conn
+            .execute("DELETE FROM decks WHERE id = ?1", [id.0])?;
+        Ok(deleted > 0)
+    }
+
+    /// The deck with the given identifier.
+    pub fn get(&self, id: DeckId) -> Result<Option<StoredDeck>, StoreError> {
+        let row = self
+            .conn
+            .query_row(
+                "SELECT name, notes, code, created_at, updated_at FROM decks WHERE id = ?1",
+                [id.0],
+                |row| {
+                    Ok((
+                        row.get::<_, String>(0)?,
+                        row.get::<_, String>(1)?,
+                        row.get::<_, String>(2)?,
+                        row.get::<_, u64>(3)?,
+                        row.get::<_, u64>(4)?,
+                    ))
+                },
+            )
+            .optional()?;
+        let Some((name, notes, code, created_at, updated_at)) = row else {
+            return Ok(None);
+        };
+
+        let mut statement = self
+            .conn
+            .prepare("SELECT tag FROM deck_tags WHERE deck_id = ?1 ORDER BY tag")?;
+        let tags = statement
+            .query_map([id.0], |row| row.get(0))?
+            .collect::<Result<Vec<String>, _>>()?;
+
+        let deck = encoder::deck_from_code(&code)?;
+        let mut champions: Vec<Card> = deck
+            .cards()
+            .iter()
+            .map(|card_count| card_count.card())
+            .filter(|card| self.champions.contains(card))
+            .cloned()
+            .collect();
+        champions.sort();
+        champions.dedup();
+
+        Ok(Some(StoredDeck {
+            id,
+            name,
+            notes,
+            tags,
+            code,
+            deck,
+            champions,
+            created_at,
+            updated_at,
+        }))
+    }
+
+    /// The deck saved with the same normalized code as `code`.
+    pub fn find(&self, code: &str) -> Result<Option<StoredDeck>, StoreError> {
+        match self.find_id(&encoder::normalize_code(code)?)? {
+            Some(id) => self.get(id),
+            None => Ok(None),
+        }
+    }
+
+    /// The decks matching `query`, ordered by name.
+    ///
+    /// Fails with [`StoreError::UnknownChampion`] if the query has a champion that is not one
+    /// of the store's champions.
+    ///
+    /// [`StoreError::UnknownChampion`]: enum.StoreError.html#variant.UnknownChampion
+    pub fn search(&self, query: &DeckQuery) -> Result<Vec<StoredDeck>, StoreError> {
+        if let Some(champion) = query
+            .champions
+            .iter()
+            .find(|champion| !self.champions.contains(champion))
+        {
+            return Err(StoreError::UnknownChampion(champion.clone()));
+        }
+
+        let mut sql = String::from("SELECT id FROM decks WHERE 1");
+        let mut values = Vec::new();
+        for card in query.cards.iter().chain(&query.champions) {
+            sql.push_str(
+                " AND EXISTS (SELECT 1 FROM deck_cards WHERE deck_id = decks.id AND card = ?)",
+            );
+            values.push(card.to_string());
+        }
+        for region in &query.regions {
+            sql.push_str(
+                " AND EXISTS (SELECT 1 FROM deck_cards WHERE deck_id = decks.id AND region = ?)",
+            );
+            values.push(region.clone());
+        }
+        for tag in &query.tags {
+            sql.push_str(
+                " AND EXISTS (SELECT 1 FROM deck_tags WHERE deck_id = decks.id AND tag = ?)",
+            );
+            values.push(tag.clone());
+        }
+        if let Some(name) = &query.name {
+            sql.push_str(" AND instr(lower(name), lower(?)) > 0");
+            values.push(name.clone());
+        }
+        sql.push_str(" ORDER BY name COLLATE NOCASE, id");
+
+        let mut statement = self.conn.prepare(&sql)?;
+        let ids = statement
+            .query_map(params_from_iter(values), |row| row.get(0))?
+            .collect::<Result<Vec<i64>, _>>()?;
+
+        ids.into_iter()
+            .filter_map(|id| self.get(DeckId(id)).transpose())
+            .collect()
+    }
+
+    /// Every code the deck has had, oldest first.
+    pub fn history(&self, id: DeckId) -> Result<Vec<DeckVersion>, StoreError> {
+        self.check_exists(id)?;
+        let mut statement = self.conn.prepare(
+            "SELECT version, code, saved_at FROM deck_versions WHERE deck_id = ?1 ORDER BY version",
+        )?;
+        let rows = statement
+            .query_map([id.0], |row| {
+                Ok((
+                    row.get::<_, u32>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, u64>(2)?,
+                ))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut previous = Deck::new();
+        let mut versions = Vec::with_capacity(rows.len());
+        for (version, code, saved_at) in rows {
+            let deck = encoder::deck_from_code(&code)?;
+            let changes = if version == 1 {
+                DeckDiff::default()
+            } else {
+                previous.diff(&deck)
+            };
+            versions.push(DeckVersion {
+                version,
+                code,
+                saved_at,
+                changes,
+            });
+            previous = deck;
+        }
+
+        Ok(versions)
+    }
+
+    /// The versions of the deck as a [`DeckHistory`] named after the deck, with the time each
+    /// version was saved as its timestamp.
+    ///
+    /// [`DeckHistory`]: ../history/struct.DeckHistory.html
+    pub fn deck_history(&self, id: DeckId) -> Result<DeckHistory, StoreError> {
+        let stored = self.get(id)?.ok_or(StoreError::DeckNotFound)?;
+        let mut history = DeckHistory::new(stored.name());
+        for version in self.history(id)? {
+            history.record(version.code(), version.saved_at())?;
+        }
+
+        Ok(history)
+    }
+
+    fn find_id(&self, code: &str) -> Result<Option<DeckId>, StoreError> {
+        let id = self
+            .conn
+            .query_row("SELECT id FROM decks WHERE code = ?1", [code], |row| {
+                row.get(0)
+            })
+            .optional()?;
+        Ok(id.map(DeckId))
+    }
+
+    fn check_exists(&self, id: DeckId) -> Result<(), StoreError> {
+        let exists: bool = self.conn.query_row(
+            "SELECT EXISTS (SELECT 1 FROM decks WHERE id = ?1)",
+            [id.0],
+            |row| row.get(0),
+        )?;
+        if exists {
+            Ok(())
+        } else {
+            Err(StoreError::DeckNotFound)
+        }
+    }
+
+    fn update_row(&mut self, sql: &str, id: DeckId, value: &str) -> Result<(), StoreError> {
+        match self.conn.execute(sql, params![id.0, value])? {
+            0 => Err(StoreError::DeckNotFound),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Records `deck` as the cards and the given version of the stored deck `id`.
+fn insert_version(
+    tx: &Transaction,
+    id: DeckId,
+    code: &str,
+    deck: &Deck,
+    version: u32,
+    saved_at: u64,
+) -> Result<(), StoreError> {
+    let mut insert_card = tx.prepare(
+        "INSERT INTO deck_cards (deck_id, card, region, count) VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT (deck_id, card) DO UPDATE SET count = count + excluded.count",
+    )?;
+    for card_count in deck.cards() {
+        let card = card_count.card();
+        insert_card.execute(params![
+            id.0,
+            card.to_string(),
+            card.faction_key(),
+            card_count.count()
+        ])?;
+    }
+
+    tx.execute(
+        "INSERT INTO deck_versions (deck_id, version, code, saved_at) VALUES (?1, ?2, ?3, ?4)",
+        params![id.0, version, code, saved_at],
+    )?;
+
+    Ok(())
+}
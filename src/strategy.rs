@@ -0,0 +1,114 @@
+use proptest::arbitrary::Arbitrary;
+use proptest::collection::{btree_map, SizeRange};
+use proptest::prelude::*;
+use proptest::sample::select;
+
+use crate::card::{faction_number_to_version, factions, Card, CardCodeAndCount};
+use crate::deck::Deck;
+use crate::encoder::MAX_KNOWN_VERSION;
+
+/// Cards with a known faction from any version of the format.
+///
+/// Sets range from 0 to 99 and numbers from 0 to 999, so that every card has a card code.
+pub fn card() -> impl Strategy<Value = Card> {
+    card_up_to_version(MAX_KNOWN_VERSION)
+}
+
+/// Cards with a faction known in `version` of the format or earlier.
+///
+/// # Panics
+///
+/// Panics if `version` is 0, as no faction is part of it.
+pub fn card_up_to_version(version: u8) -> impl Strategy<Value = Card> {
+    let factions: Vec<u32> = factions()
+        .filter(|&faction| faction_number_to_version(faction).is_some_and(|v| v <= version))
+        .collect();
+
+    (0..=99u32, select(factions), 0..=999u32)
+        .prop_map(|(set, faction, number)| Card::new(set, faction, number))
+}
+
+/// Card counts, mostly from 1 to 3 but sometimes up to 40, which decks encode separately.
+pub fn count() -> impl Strategy<Value = i32> {
+    prop_oneof![9 => 1..=3, 1 => 4..=40]
+}
+
+/// Cards from [`card`] with a count from [`count`].
+///
+/// [`card`]: fn.card.html
+/// [`count`]: fn.count.html
+pub fn card_code_and_count() -> impl Strategy<Value = CardCodeAndCount> {
+    (card(), count()).prop_map(|(card, count)| CardCodeAndCount::new(card, count))
+}
+
+/// Decks of up to 40 different cards from [`card`], with counts from [`count`].
+///
+/// # Examples
+/// ```
+/// use lordeckcodes::{encoder, strategy};
+/// use proptest::prelude::*;
+///
+/// proptest!(|(deck in strategy::deck())| {
+///     prop_assert!(encoder::code_from_deck(&deck).is_ok());
+/// });
+/// ```
+///
+/// [`card`]: fn.card.html
+/// [`count`]: fn.count.html
+pub fn deck() -> impl Strategy<Value = Deck> {
+    deck_of(card(), 0..=40)
+}
+
+/// Decks holding a number of different cards in `size` taken from `cards`, with counts from
+/// [`count`].
+///
+/// [`count`]: fn.count.html
+pub fn deck_of(
+    cards: impl Strategy<Value = Card>,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = Deck> {
+    btree_map(cards, count(), size).prop_map(|cards| {
+        Deck::from_vec(
+            cards
+                .into_iter()
+                .map(|(card, count)| CardCodeAndCount::new(card, count))
+                .collect(),
+        )
+    })
+}
+
+/// Generates cards with [`card`].
+///
+/// [`card`]: fn.card.html
+impl Arbitrary for Card {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Card>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        card().boxed()
+    }
+}
+
+/// Generates cards with [`card_code_and_count`].
+///
+/// [`card_code_and_count`]: fn.card_code_and_count.html
+impl Arbitrary for CardCodeAndCount {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<CardCodeAndCount>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        card_code_and_count().boxed()
+    }
+}
+
+/// Generates decks with [`deck`].
+///
+/// [`deck`]: fn.deck.html
+impl Arbitrary for Deck {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Deck>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        deck().boxed()
+    }
+}
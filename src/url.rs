@@ -0,0 +1,279 @@
+use crate::deck::Deck;
+use crate::encoder;
+use crate::error::LorError;
+
+/// Placeholder marking the position of the deck code in a [`UrlPattern`] template.
+///
+/// [`UrlPattern`]: struct.UrlPattern.html
+pub const CODE_PLACEHOLDER: &str = "{code}";
+
+/// Where the code sits in a URL matched by a [`UrlPattern`].
+///
+/// [`UrlPattern`]: struct.UrlPattern.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Location {
+    /// Between a path prefix and a path suffix.
+    Path { prefix: String, suffix: String },
+    /// As the value of a query parameter on the given path.
+    Query { path: String, key: String },
+    /// In the fragment of the given path, either as the whole fragment or as the value of a
+    /// `key=value` pair.
+    Fragment { path: String, key: Option<String> },
+}
+
+/// A URL shape used by a deck site to share decks, such as
+/// `https://example.com/decks/{code}`, `https://example.com/builder?deck={code}` or
+/// `https://example.com/builder#{code}`.
+///
+/// Matching ignores the scheme, a leading `www.` and the case of the host, as well as any
+/// query parameters or fragment not holding the code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UrlPattern {
+    name: String,
+    template: String,
+    location: Location,
+}
+
+impl UrlPattern {
+    /// Create a `UrlPattern` from a template containing [`CODE_PLACEHOLDER`] once, either in
+    /// its path, as the value of a query parameter or in its fragment.
+    ///
+    /// [`CODE_PLACEHOLDER`]: constant.CODE_PLACEHOLDER.html
+    pub fn new(name: &str, template: &str) -> Result<UrlPattern, LorError> {
+        if template.matches(CODE_PLACEHOLDER).count() != 1 {
+            return Err(LorError::InvalidUrl);
+        }
+
+        let (url, fragment) = split_once_opt(strip_scheme(template), '#');
+        let (path, query) = split_once_opt(url, '?');
+        let location = if let Some(fragment) = fragment.filter(|f| f.contains(CODE_PLACEHOLDER)) {
+            let key = if fragment == CODE_PLACEHOLDER {
+                None
+            } else {
+                Some(placeholder_key(fragment)?.to_string())
+            };
+            Location::Fragment {
+                path: normalize_path(path.trim_end_matches('/')),
+                key,
+            }
+        } else if let Some(query) = query.filter(|q| q.contains(CODE_PLACEHOLDER)) {
+            Location::Query {
+                path: normalize_path(path.trim_end_matches('/')),
+                key: placeholder_key(query)?.to_string(),
+            }
+        } else {
+            // the code has to be in the path, after the host
+            let (prefix, suffix) = path
+                .split_once(CODE_PLACEHOLDER)
+                .filter(|(prefix, _)| prefix.contains('/'))
+                .ok_or(LorError::InvalidUrl)?;
+            Location::Path {
+                prefix: normalize_path(prefix),
+                suffix: suffix.trim_end_matches('/').to_string(),
+            }
+        };
+
+        Ok(UrlPattern {
+            name: name.to_string(),
+            template: template.to_string(),
+            location,
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn template(&self) -> &str {
+        &self.template
+    }
+
+    /// Extract the deck code from `url`, if the URL has this shape.
+    pub fn extract<'a>(&self, url: &'a str) -> Option<&'a str> {
+        let (url, fragment) = split_once_opt(strip_scheme(url.trim()), '#');
+        let (path, query) = split_once_opt(url, '?');
+        let (host, rest) = path.split_once('/').unwrap_or((path, ""));
+        let host = host.strip_prefix("www.").unwrap_or(host);
+
+        let code = match &self.location {
+            Location::Path { prefix, suffix } => {
+                let (prefix_host, prefix_rest) = prefix.split_once('/').unwrap_or((prefix, ""));
+                if !host.eq_ignore_ascii_case(prefix_host) {
+                    return None;
+                }
+
+                let code = rest
+                    .strip_prefix(prefix_rest)?
+                    .trim_end_matches('/')
+                    .strip_suffix(suffix.as_str())?;
+                if code.contains('/') {
+                    return None;
+                }
+                code
+            }
+            Location::Query { path, key } => {
+                if !same_path(path, host, rest) {
+                    return None;
+                }
+                pair_value(query?, key)?
+            }
+            Location::Fragment { path, key } => {
+                if !same_path(path, host, rest) {
+                    return None;
+                }
+                match key {
+                    Some(key) => pair_value(fragment?, key)?,
+                    None => fragment?,
+                }
+            }
+        };
+
+        Some(code).filter(|code| !code.is_empty())
+    }
+
+    /// Build the URL sharing the deck with the provided code.
+    pub fn build(&self, code: &str) -> String {
+        self.template.replacen(CODE_PLACEHOLDER, code, 1)
+    }
+}
+
+/// Names and URL templates of the deck sites registered by [`UrlRegistry::with_known_sites`].
+///
+/// [`UrlRegistry::with_known_sites`]: struct.UrlRegistry.html#method.with_known_sites
+pub const KNOWN_SITES: [(&str, &str); 6] = [
+    ("mobalytics", "https://lor.mobalytics.gg/decks/code/{code}"),
+    ("runeterra.ar", "https://runeterra.ar/decks/code/{code}"),
+    ("runeterraccg", "https://runeterraccg.com/decks/code/{code}"),
+    (
+        "masteringruneterra",
+        "https://masteringruneterra.com/deck-builder?code={code}",
+    ),
+    ("dotgg", "https://lor.dotgg.gg/deck-builder/?deck={code}"),
+    ("playruneterra", "https://playruneterra.com/deck/{code}"),
+];
+
+/// A collection of [`UrlPattern`] used to read and create deck URLs.
+///
+/// [`with_known_sites`] creates a registry knowing the URL shapes of popular deck sites, and
+/// more can be added with [`register`].
+///
+/// # Examples
+/// ```
+/// use lordeckcodes::url::{UrlPattern, UrlRegistry};
+///
+/// let mut registry = UrlRegistry::with_known_sites();
+/// registry.register(UrlPattern::new("example", "https://example.com/deck?code={code}").unwrap());
+///
+/// let deck = registry.deck_from_url(
+///     "https://example.com/deck?lang=en&code=CEBAIAIFB4WDANQIAEAQGDAUDAQSIJZUAIAQCAIEAEAQKBIA",
+/// );
+/// assert!(deck.is_ok());
+///
+/// let url = registry.share_url("mobalytics", &deck.unwrap()).unwrap();
+/// assert_eq!(
+///     url,
+///     "https://lor.mobalytics.gg/decks/code/CEBAIAIFB4WDANQIAEAQGDAUDAQSIJZUAIAQCAIEAEAQKBIA"
+/// );
+/// ```
+///
+/// [`UrlPattern`]: struct.UrlPattern.html
+/// [`with_known_sites`]: struct.UrlRegistry.html#method.with_known_sites
+/// [`register`]: struct.UrlRegistry.html#method.register
+#[derive(Debug, Clone, Default)]
+pub struct UrlRegistry {
+    patterns: Vec<UrlPattern>,
+}
+
+impl UrlRegistry {
+    /// Create a new `UrlRegistry` without any pattern.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Create a `UrlRegistry` with the patterns of [`KNOWN_SITES`].
+    ///
+    /// [`KNOWN_SITES`]: constant.KNOWN_SITES.html
+    pub fn with_known_sites() -> Self {
+        let mut registry = UrlRegistry::new();
+        for (name, template) in KNOWN_SITES {
+            registry.register(UrlPattern::new(name, template).unwrap());
+        }
+        registry
+    }
+
+    /// Add a pattern, replacing any existing pattern with the same name.
+    pub fn register(&mut self, pattern: UrlPattern) {
+        self.patterns.retain(|p| p.name != pattern.name);
+        self.patterns.push(pattern);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&UrlPattern> {
+        self.patterns.iter().find(|p| p.name == name)
+    }
+
+    pub fn patterns(&self) -> &[UrlPattern] {
+        &self.patterns
+    }
+
+    /// Extract the deck code from `url` using the first matching pattern.
+    pub fn extract<'a>(&self, url: &'a str) -> Option<&'a str> {
+        self.patterns.iter().find_map(|p| p.extract(url))
+    }
+
+    /// Obtain the deck shared by `url`.
+    pub fn deck_from_url(&self, url: &str) -> Result<Deck, LorError> {
+        let code = self.extract(url).ok_or(LorError::InvalidUrl)?;
+        encoder::deck_from_code(code)
+    }
+
+    /// Build the URL sharing `deck` on the site registered as `name`.
+    pub fn share_url(&self, name: &str, deck: &Deck) -> Result<String, LorError> {
+        let pattern = self.get(name).ok_or(LorError::InvalidUrl)?;
+        Ok(pattern.build(&encoder::code_from_deck(deck)?))
+    }
+}
+
+fn strip_scheme(url: &str) -> &str {
+    url.split_once("://").map_or(url, |(_, rest)| rest)
+}
+
+fn split_once_opt(url: &str, separator: char) -> (&str, Option<&str>) {
+    match url.split_once(separator) {
+        Some((before, after)) => (before, Some(after)),
+        None => (url, None),
+    }
+}
+
+/// Key of the `key={code}` pair of a template query or fragment.
+fn placeholder_key(pairs: &str) -> Result<&str, LorError> {
+    pairs
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(_, value)| *value == CODE_PLACEHOLDER)
+        .map(|(key, _)| key)
+        .filter(|key| !key.is_empty())
+        .ok_or(LorError::InvalidUrl)
+}
+
+/// Value of `key` in `&`-separated `key=value` pairs.
+fn pair_value<'a>(pairs: &'a str, key: &str) -> Option<&'a str> {
+    pairs
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(k, _)| *k == key)
+        .map(|(_, value)| value)
+}
+
+/// Whether the host and the rest of a URL path match the normalized `path` of a pattern.
+fn same_path(path: &str, host: &str, rest: &str) -> bool {
+    let (path_host, path_rest) = path.split_once('/').unwrap_or((path, ""));
+    host.eq_ignore_ascii_case(path_host) && rest.trim_end_matches('/') == path_rest
+}
+
+fn normalize_path(path: &str) -> String {
+    let path = path.strip_prefix("www.").unwrap_or(path);
+    match path.split_once('/') {
+        Some((host, rest)) => format!("{}/{}", host.to_ascii_lowercase(), rest),
+        None => path.to_ascii_lowercase(),
+    }
+}
@@ -0,0 +1,74 @@
+use alloc::vec::Vec;
+
+use thiserror::Error;
+
+/// Maximum number of bytes used by the varint encoding of a `u32`.
+pub const MAX_U32_LEN: usize = 5;
+
+/// A malformed varint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum VarintError {
+    /// The input ended before the last byte of the varint.
+    #[error("truncated varint")]
+    Truncated,
+    /// The varint has trailing zero bytes, so it is longer than needed for its value.
+    #[error("overlong varint")]
+    Overlong,
+    /// The value does not fit in a `u32`.
+    #[error("varint overflows u32")]
+    Overflow,
+}
+
+/// Reads an unsigned LEB128 varint from `input`, consuming the bytes of the value.
+///
+/// Only the shortest encoding of a value is accepted.
+///
+/// # Examples
+/// ```
+/// use lordeckcodes::varint::{self, VarintError};
+///
+/// assert_eq!(varint::read_u32(&mut [0xAC, 0x02].into_iter()), Ok(300));
+/// assert_eq!(varint::read_u32(&mut [0xAC].into_iter()), Err(VarintError::Truncated));
+/// assert_eq!(varint::read_u32(&mut [0x80, 0x00].into_iter()), Err(VarintError::Overlong));
+/// ```
+pub fn read_u32<I: Iterator<Item = u8>>(input: &mut I) -> Result<u32, VarintError> {
+    let mut value = 0u32;
+
+    for i in 0..MAX_U32_LEN {
+        let byte = input.next().ok_or(VarintError::Truncated)?;
+        let bits = u32::from(byte & 0x7F);
+
+        // the last byte only holds the 4 highest bits of the value
+        if i == MAX_U32_LEN - 1 && (byte & 0x80 != 0 || bits > 0x0F) {
+            return Err(VarintError::Overflow);
+        }
+        value |= bits << (7 * i);
+
+        if byte & 0x80 == 0 {
+            if i > 0 && byte == 0 {
+                return Err(VarintError::Overlong);
+            }
+            return Ok(value);
+        }
+    }
+
+    unreachable!("the last byte of a varint either ends it or overflows")
+}
+
+/// Appends the unsigned LEB128 varint encoding of `value` to `output`.
+///
+/// # Examples
+/// ```
+/// use lordeckcodes::varint;
+///
+/// let mut bytes = vec![];
+/// varint::write_u32(&mut bytes, 300);
+/// assert_eq!(bytes, [0xAC, 0x02]);
+/// ```
+pub fn write_u32(output: &mut Vec<u8>, mut value: u32) {
+    while value >= 0x80 {
+        output.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    output.push(value as u8);
+}
@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+use crate::card::CardCodeAndCount;
+use crate::deck::Deck;
+use crate::encoder;
+
+#[wasm_bindgen(typescript_custom_section)]
+const DECK_CARD: &'static str = r#"
+/** A card of a deck with its number of copies. */
+export interface DeckCard {
+    /** Card code, such as `"01SI015"`. */
+    code: string;
+    count: number;
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    /// The cards of a deck, as a JavaScript array of `DeckCard` objects.
+    #[wasm_bindgen(typescript_type = "DeckCard[]")]
+    pub type DeckCards;
+}
+
+#[derive(Serialize, Deserialize)]
+struct DeckCard {
+    code: String,
+    count: i32,
+}
+
+/// Decodes a deck code into its cards.
+///
+/// Throws an `Error` if the code is invalid.
+#[wasm_bindgen(js_name = decodeDeck)]
+pub fn decode_deck(code: &str) -> Result<DeckCards, JsError> {
+    let deck = encoder::deck_from_code(code)?;
+    let cards: Vec<DeckCard> = deck
+        .cards()
+        .iter()
+        .map(|card_count| DeckCard {
+            code: card_count.card().to_string(),
+            count: card_count.count(),
+        })
+        .collect();
+
+    Ok(serde_wasm_bindgen::to_value(&cards)?.unchecked_into())
+}
+
+/// Generates the code of a deck made of the provided cards.
+///
+/// Throws an `Error` if a card code or count is invalid.
+#[wasm_bindgen(js_name = encodeDeck)]
+pub fn encode_deck(cards: DeckCards) -> Result<String, JsError> {
+    let cards: Vec<DeckCard> = serde_wasm_bindgen::from_value(cards.into())?;
+    let deck = cards
+        .iter()
+        .map(|card| CardCodeAndCount::from_data(&card.code, card.count))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(encoder::code_from_deck(&Deck::from_vec(deck))?)
+}
+
+/// Rewrites a deck code in its canonical form, see [`encoder::normalize_code`].
+///
+/// Throws an `Error` if the code is invalid.
+///
+/// [`encoder::normalize_code`]: ../encoder/fn.normalize_code.html
+#[wasm_bindgen(js_name = normalizeCode)]
+pub fn normalize_code(code: &str) -> Result<String, JsError> {
+    Ok(encoder::normalize_code(code)?)
+}
+
+/// Checks a deck code, returning why it is invalid or `undefined` if it is valid.
+#[wasm_bindgen(js_name = validateCode)]
+pub fn validate_code(code: &str) -> Option<String> {
+    encoder::deck_from_code(code).err().map(|e| e.to_string())
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+
+use lordeckcodes::{encoder, CardCodeAndCount, Deck};
+
+/// Pseudo-random bytes, so that the test is reproducible.
+fn bytes(seed: u64, len: usize) -> Vec<u8> {
+    let mut state = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        })
+        .collect()
+}
+
+#[test]
+fn arbitrary_decks_round_trip() {
+    for seed in 0..500 {
+        let data = bytes(seed, 256);
+        let deck = Deck::arbitrary(&mut Unstructured::new(&data)).unwrap();
+
+        let mut cards = deck.cards().clone();
+        cards.sort();
+        let mut unique = cards.clone();
+        unique.dedup_by(|a, b| a.card() == b.card());
+        assert_eq!(unique, cards);
+        assert!(cards.iter().all(|card| (1..=40).contains(&card.count())));
+
+        let code = encoder::code_from_deck(&deck).unwrap();
+        let mut decoded: Vec<CardCodeAndCount> =
+            encoder::deck_from_code(&code).unwrap().cards().clone();
+        decoded.sort();
+        assert_eq!(decoded, cards);
+    }
+}
@@ -0,0 +1,144 @@
+#![cfg(feature = "std")]
+
+use std::collections::HashSet;
+
+use lordeckcodes::cluster::{cluster_decks, consensus_code, consensus_deck, ClusterOptions};
+use lordeckcodes::{encoder, Card, CardCodeAndCount, Deck};
+
+fn deck(cards: &[(&str, i32)]) -> Deck {
+    cards.iter().collect()
+}
+
+#[test]
+fn cluster_separates_archetypes() {
+    let decks = vec![
+        deck(&[
+            ("01SI015", 3),
+            ("01SI044", 3),
+            ("01SI048", 3),
+            ("01SI054", 2),
+        ]),
+        deck(&[
+            ("01SI015", 3),
+            ("01SI044", 3),
+            ("01SI048", 3),
+            ("01SI005", 2),
+        ]),
+        deck(&[
+            ("01SI015", 3),
+            ("01SI044", 3),
+            ("01SI048", 3),
+            ("01SI054", 2),
+        ]),
+        deck(&[
+            ("01FR003", 3),
+            ("01FR012", 3),
+            ("01FR020", 3),
+            ("01FR024", 1),
+        ]),
+        deck(&[
+            ("01FR003", 3),
+            ("01FR012", 3),
+            ("01FR020", 3),
+            ("01FR024", 1),
+        ]),
+    ];
+
+    let clusters = cluster_decks(&decks, &ClusterOptions::new(2)).unwrap();
+    assert_eq!(clusters.len(), 2);
+
+    assert_eq!(clusters[0].members(), &[0, 1, 2]);
+    assert_eq!(
+        clusters[0].core(),
+        &[
+            CardCodeAndCount::from_data("01SI015", 3).unwrap(),
+            CardCodeAndCount::from_data("01SI044", 3).unwrap(),
+            CardCodeAndCount::from_data("01SI048", 3).unwrap(),
+        ]
+    );
+    assert_eq!(clusters[0].flex().len(), 2);
+    assert_eq!(clusters[0].flex()[0].card().to_string(), "01SI054");
+    assert_eq!(
+        clusters[0].representative_code(),
+        encoder::code_from_deck(&decks[0]).unwrap()
+    );
+
+    assert_eq!(clusters[1].members(), &[3, 4]);
+    assert_eq!(clusters[1].core().len(), 4);
+    assert!(clusters[1].flex().is_empty());
+}
+
+#[test]
+fn cluster_identical_decks() {
+    let decks = vec![
+        deck(&[("01SI015", 3), ("01SI044", 3)]),
+        deck(&[("01SI015", 3), ("01SI044", 3)]),
+    ];
+
+    let clusters = cluster_decks(&decks, &ClusterOptions::new(3)).unwrap();
+    assert_eq!(clusters.len(), 1);
+    assert_eq!(clusters[0].members(), &[0, 1]);
+
+    assert!(cluster_decks(&[], &ClusterOptions::new(3))
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn cluster_huge_counts() {
+    let decks = vec![
+        deck(&[("01SI015", i32::MAX), ("01SI015", i32::MAX)]),
+        deck(&[("01SI044", i32::MAX)]),
+        deck(&[("01SI015", i32::MAX)]),
+    ];
+
+    let clusters = cluster_decks(&decks, &ClusterOptions::new(2)).unwrap();
+    assert_eq!(clusters.len(), 2);
+    assert_eq!(clusters[0].members(), &[0, 2]);
+    assert_eq!(clusters[1].members(), &[1]);
+}
+
+#[test]
+fn consensus_respects_deck_constraints() {
+    let champion_codes = ["01SI052", "01SI053", "01SI054"];
+    let champions: HashSet<Card> = champion_codes
+        .iter()
+        .map(|code| CardCodeAndCount::from_data(code, 1).unwrap().card().clone())
+        .collect();
+
+    // every deck plays 3 copies of each champion, while each filler card is only in two of
+    // the three decks, so champion copies are picked first and the cap has to stop them
+    let mut decks = vec![];
+    for i in 0..3 {
+        let mut deck = Deck::new();
+        for code in champion_codes {
+            deck.add_from_data(code, 3).unwrap();
+        }
+        for j in (1..=20).filter(|j| j % 3 != i) {
+            deck.add_from_data(&format!("01FR{j:03}"), 3).unwrap();
+        }
+        decks.push(deck);
+    }
+
+    let consensus = consensus_deck(&decks, &champions);
+    let total: i32 = consensus.cards().iter().map(|c| c.count()).sum();
+    assert_eq!(total, Deck::SIZE);
+    assert!(consensus
+        .cards()
+        .iter()
+        .all(|c| c.count() <= Deck::MAX_COPIES));
+
+    let picked: Vec<String> = consensus
+        .cards()
+        .iter()
+        .filter(|c| champions.contains(c.card()))
+        .map(|c| format!("{}:{}", c.card(), c.count()))
+        .collect();
+    assert_eq!(picked, ["01SI052:3", "01SI053:3"]);
+
+    let code = consensus_code(&decks, &champions).unwrap();
+    assert!(encoder::deck_from_code(code)
+        .unwrap()
+        .diff(&consensus)
+        .is_empty());
+}
@@ -0,0 +1,108 @@
+#![cfg(feature = "std")]
+
+use lordeckcodes::database::{CardDatabase, CardInfo};
+use lordeckcodes::decklist::parse_decklist;
+use lordeckcodes::{CardCodeAndCount, Deck};
+
+fn database() -> CardDatabase {
+    vec![
+        CardInfo::new("01PZ036", "Ezreal", 3, true).unwrap(),
+        CardInfo::new("01PZ052", "Mystic Shot", 2, false).unwrap(),
+        CardInfo::new("01DE045", "Vi's Crossbow", 2, false).unwrap(),
+        CardInfo::new("01FR024", "Anivia", 7, true).unwrap(),
+    ]
+    .into_iter()
+    .collect()
+}
+
+#[test]
+fn parse_decklist_formats() {
+    let text = "
+        # Ezreal Anivia
+        2x Ezreal
+        2 MYSTIC SHOT
+        vis crossbow x1
+        3 x 01FR024
+        1 Ezreal
+    ";
+
+    let import = parse_decklist(text, &database());
+    assert!(import.is_complete());
+
+    let expected = Deck::from_vec(vec![
+        CardCodeAndCount::from_data("01PZ036", 3).unwrap(),
+        CardCodeAndCount::from_data("01PZ052", 2).unwrap(),
+        CardCodeAndCount::from_data("01DE045", 1).unwrap(),
+        CardCodeAndCount::from_data("01FR024", 3).unwrap(),
+    ]);
+    assert_eq!(import.into_deck(), expected);
+}
+
+#[test]
+fn parse_decklist_reports_unresolved_lines() {
+    let import = parse_decklist("3 Ezreal\n3 Ezrael\nAnivia\n2 Teemo", &database());
+
+    assert_eq!(import.deck().cards().len(), 1);
+
+    let unresolved = import.unresolved();
+    assert_eq!(unresolved.len(), 3);
+    assert_eq!(unresolved[0].line(), 2);
+    assert_eq!(unresolved[0].text(), "3 Ezrael");
+    assert_eq!(unresolved[0].suggestions(), &["Ezreal"]);
+    assert_eq!(unresolved[1].suggestions(), &["Anivia"]);
+    assert!(unresolved[2].suggestions().is_empty());
+}
+
+#[test]
+fn parse_decklist_reports_lines_over_copy_limit() {
+    let text = "2 Ezreal\n2 Ezreal\n1 Ezreal\n4 Anivia\n1 Mystic Shot\n2147483647 Mystic Shot";
+    let import = parse_decklist(text, &database());
+
+    let expected = Deck::from_vec(vec![
+        CardCodeAndCount::from_data("01PZ036", 3).unwrap(),
+        CardCodeAndCount::from_data("01PZ052", 1).unwrap(),
+    ]);
+    assert_eq!(import.deck(), &expected);
+
+    let lines: Vec<usize> = import.unresolved().iter().map(|l| l.line()).collect();
+    assert_eq!(lines, [2, 4, 6]);
+    assert!(import.unresolved()[0].suggestions().is_empty());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn database_from_data_dragon() {
+    let json = r#"[
+        {"cardCode": "01SI053", "name": "Elise", "cost": 2, "rarityRef": "Champion", "collectible": true},
+        {"cardCode": "01SI053T1", "name": "Elise", "cost": 2, "rarityRef": "None", "collectible": false},
+        {"cardCode": "01SI015", "name": "Frenzied Skitterer", "cost": 2, "rarityRef": "Common", "collectible": true}
+    ]"#;
+
+    let database: CardDatabase = serde_json::from_str(json).unwrap();
+    assert_eq!(database.len(), 2);
+    assert!(database.find_by_name("Elise").unwrap().is_champion());
+    assert_eq!(database.champions().len(), 1);
+}
+
+#[test]
+fn database_renames_cards() {
+    let mut database = database();
+    database.insert(CardInfo::new("01PZ036", "Ezreal, the Prodigal", 3, true).unwrap());
+    assert!(database.find_by_name("Ezreal").is_none());
+    assert_eq!(
+        database.find_by_name("ezreal the prodigal").unwrap().name(),
+        "Ezreal, the Prodigal"
+    );
+
+    database.insert(CardInfo::new("02PZ008", "Mystic Shot", 2, false).unwrap());
+    database.insert(CardInfo::new("01PZ052", "Mystic Shot (old)", 2, false).unwrap());
+    assert_eq!(
+        database
+            .find_by_name("Mystic Shot")
+            .unwrap()
+            .card()
+            .to_string(),
+        "02PZ008"
+    );
+    assert_eq!(database.len(), 5);
+}
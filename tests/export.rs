@@ -0,0 +1,65 @@
+#![cfg(feature = "std")]
+
+use lordeckcodes::database::{CardDatabase, CardInfo};
+use lordeckcodes::{export, Deck};
+
+fn database() -> CardDatabase {
+    vec![
+        CardInfo::new("01SI053", "Elise", 2, true).unwrap(),
+        CardInfo::new("01SI015", "Frenzied Skitterer", 2, false).unwrap(),
+        CardInfo::new("01SI005", "Vile Feast", 1, false).unwrap(),
+        CardInfo::new("01FR024", "Anivia", 7, true).unwrap(),
+        CardInfo::new("01FR003", "Rimefang Wolf, \"Alpha\"", 3, false).unwrap(),
+    ]
+    .into_iter()
+    .collect()
+}
+
+fn deck() -> Deck {
+    [
+        ("01SI053", 3),
+        ("01FR024", 2),
+        ("01SI005", 2),
+        ("01FR003", 3),
+        ("01SI015", 3),
+        ("01FR099", 1),
+    ]
+    .iter()
+    .collect()
+}
+
+#[test]
+fn export_text() {
+    assert_eq!(
+        export::to_text(&deck(), Some(&database())),
+        "3x Rimefang Wolf, \"Alpha\"\n2x Anivia\n1x 01FR099\n\
+         2x Vile Feast\n3x Elise\n3x Frenzied Skitterer\n"
+    );
+    assert!(export::to_text(&deck(), None).starts_with("3x 01FR003\n"));
+}
+
+#[test]
+fn export_markdown() {
+    let markdown = export::to_markdown(&deck(), Some(&database()));
+    assert!(markdown.starts_with("### Freljord\n\n| Cost | Card | Code | Count |\n"));
+    assert!(markdown.contains("| 7 | Anivia | 01FR024 | 2 |\n|  | 01FR099 | 01FR099 | 1 |\n"));
+    assert!(markdown.contains("\n### Shadow Isles\n\n"));
+}
+
+#[test]
+fn export_csv() {
+    let csv = export::to_csv(&deck(), Some(&database()));
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines[0], "code,name,count,cost");
+    assert_eq!(lines[1], "01FR003,\"Rimefang Wolf, \"\"Alpha\"\"\",3,3");
+    assert_eq!(lines[3], "01FR099,01FR099,1,");
+    assert_eq!(lines.len(), 7);
+}
+
+#[test]
+fn export_html() {
+    let html = export::to_html(&deck(), Some(&database()));
+    assert!(html.starts_with("<div class=\"deck\">\n  <h3>Freljord</h3>\n  <ul>\n"));
+    assert!(html.contains("<li data-code=\"01FR003\"><span class=\"count\">3x</span> Rimefang Wolf, &quot;Alpha&quot;</li>"));
+    assert!(html.ends_with("  </ul>\n</div>\n"));
+}
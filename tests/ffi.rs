@@ -0,0 +1,49 @@
+#![cfg(all(feature = "ffi", target_os = "linux"))]
+
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Builds `tests/ffi/main.c` against the shared library and the committed header, then
+/// runs it.
+#[test]
+fn c_program() {
+    // a separate target directory avoids waiting on the lock held by `cargo test`
+    let target_dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("ffi");
+    let status = Command::new(env!("CARGO"))
+        .args([
+            "rustc",
+            "--lib",
+            "--features",
+            "ffi",
+            "--crate-type",
+            "cdylib",
+        ])
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let lib_dir = target_dir.join("debug");
+    let program = lib_dir.join("ffi_main");
+    let status = Command::new(env::var("CC").unwrap_or_else(|_| "cc".to_string()))
+        .arg("tests/ffi/main.c")
+        .arg("-Iinclude")
+        .arg("-Wall")
+        .arg("-Werror")
+        .arg("-L")
+        .arg(&lib_dir)
+        .arg("-llordeckcodes")
+        .arg("-o")
+        .arg(&program)
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let status = Command::new(&program)
+        .env("LD_LIBRARY_PATH", &lib_dir)
+        .status()
+        .unwrap();
+    assert!(status.success());
+}
@@ -0,0 +1,70 @@
+#include <stdio.h>
+#include <string.h>
+
+#include "lordeckcodes.h"
+
+#define CODE "CEBAIAIFB4WDANQIAEAQGDAUDAQSIJZUAIAQCAIEAEAQKBIA"
+
+#define CHECK(condition)                                                    \
+    do {                                                                    \
+        if (!(condition)) {                                                 \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, \
+                    #condition);                                            \
+            return 1;                                                       \
+        }                                                                   \
+    } while (0)
+
+static int decode_encode(void) {
+    LorDeck *deck = NULL;
+    CHECK(lor_deck_from_code(CODE, &deck) == LOR_ERROR_CODE_OK);
+    CHECK(lor_deck_len(deck) == 14);
+
+    LorCardCount card;
+    CHECK(lor_deck_get(deck, 0, &card) == LOR_ERROR_CODE_OK);
+    CHECK(strcmp(card.code, "01SI015") == 0);
+    CHECK(card.count == 3);
+    CHECK(lor_deck_get(deck, 14, &card) == LOR_ERROR_CODE_INDEX_OUT_OF_BOUNDS);
+
+    char *code = NULL;
+    CHECK(lor_deck_to_code(deck, &code) == LOR_ERROR_CODE_OK);
+    CHECK(strcmp(code, CODE) == 0);
+
+    lor_string_free(code);
+    lor_deck_free(deck);
+    return 0;
+}
+
+static int build(void) {
+    LorDeck *deck = lor_deck_new();
+    CHECK(lor_deck_add(deck, "01SI015", 3) == LOR_ERROR_CODE_OK);
+    CHECK(lor_deck_add(deck, "01XX015", 3) == LOR_ERROR_CODE_INVALID_CARD);
+    CHECK(lor_deck_add(deck, "01SI015", 0) == LOR_ERROR_CODE_INVALID_CARD);
+    CHECK(lor_deck_len(deck) == 1);
+
+    char *code = NULL;
+    CHECK(lor_deck_to_code(deck, &code) == LOR_ERROR_CODE_OK);
+    CHECK(strlen(code) > 0);
+
+    lor_string_free(code);
+    lor_deck_free(deck);
+    return 0;
+}
+
+static int errors(void) {
+    LorDeck *deck = NULL;
+    CHECK(lor_deck_from_code("not a code", &deck) == LOR_ERROR_CODE_DECODE);
+    CHECK(deck == NULL);
+    CHECK(lor_deck_from_code(NULL, &deck) == LOR_ERROR_CODE_NULL_POINTER);
+    CHECK(strcmp(lor_error_message(LOR_ERROR_CODE_DECODE), "decode") == 0);
+    CHECK(strcmp(lor_error_message(LOR_ERROR_CODE_INDEX_OUT_OF_BOUNDS), "index out of bounds") == 0);
+    CHECK(strcmp(lor_error_message(-1), "unknown error") == 0);
+    CHECK(strcmp(lor_error_message(1000), "unknown error") == 0);
+
+    lor_deck_free(NULL);
+    lor_string_free(NULL);
+    return 0;
+}
+
+int main(void) {
+    return decode_encode() || build() || errors();
+}
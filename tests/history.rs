@@ -0,0 +1,87 @@
+#![cfg(feature = "std")]
+
+use lordeckcodes::history::DeckHistory;
+use lordeckcodes::{encoder, Deck};
+
+fn code(cards: &[(&str, i32)]) -> String {
+    encoder::code_from_deck(&cards.iter().collect()).unwrap()
+}
+
+fn history() -> DeckHistory {
+    let mut history = DeckHistory::new("Elise Spiders");
+    assert!(history
+        .record(&code(&[("01SI042", 3), ("01SI015", 3)]), 100)
+        .unwrap());
+    assert!(history
+        .record(
+            &code(&[("01SI042", 3), ("01SI015", 2), ("01SI044", 1)]),
+            200
+        )
+        .unwrap());
+    assert!(history
+        .record_deck(&[("01SI042", 3), ("01SI044", 3)].iter().collect(), 300)
+        .unwrap());
+    history
+}
+
+#[test]
+fn record() {
+    let mut history = history();
+    assert_eq!(history.name(), "Elise Spiders");
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.version(0), None);
+    assert_eq!(history.version(2).unwrap().timestamp(), 200);
+    assert_eq!(history.version(4), None);
+
+    // the same cards in another order are not a new version
+    let latest = history.latest().unwrap().clone();
+    let reordered = code(&[("01SI044", 3), ("01SI042", 3)]);
+    assert!(!history.record(&reordered.to_lowercase(), 400).unwrap());
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.latest(), Some(&latest));
+
+    let expected: Deck = [("01SI042", 3), ("01SI044", 3)].iter().collect();
+    assert!(latest.deck().diff(&expected).is_empty());
+
+    assert!(history.record("not a code", 500).is_err());
+    assert_eq!(history.len(), 3);
+}
+
+#[test]
+fn changelog() {
+    let history = history();
+    let diffs: Vec<String> = history.diffs().map(|diff| diff.to_string()).collect();
+    assert_eq!(
+        diffs,
+        [
+            "+3 01SI015 +3 01SI042",
+            "-1 01SI015 +1 01SI044",
+            "-2 01SI015 +2 01SI044",
+        ]
+    );
+    assert_eq!(
+        history.changelog(),
+        "v1: +3 01SI015 +3 01SI042\nv2: -1 01SI015 +1 01SI044\nv3: -2 01SI015 +2 01SI044\n"
+    );
+    assert_eq!(DeckHistory::new("Empty").changelog(), "");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    let history = history();
+    let json = serde_json::to_value(&history).unwrap();
+    assert_eq!(json["name"], "Elise Spiders");
+    assert_eq!(json["entries"][1]["timestamp"], 200);
+    assert_eq!(
+        json["entries"][1]["code"],
+        history.version(2).unwrap().code()
+    );
+
+    let deserialized: DeckHistory = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(deserialized, history);
+
+    let mut invalid = json;
+    invalid["entries"][0]["code"] = "not a code".into();
+    assert!(serde_json::from_value::<DeckHistory>(invalid).is_err());
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "std")]
+
+use std::collections::HashSet;
+
+use lordeckcodes::lineup::{Lineup, LineupRules, LineupViolation};
+use lordeckcodes::{Card, CardCodeAndCount, Deck};
+
+fn card(code: &str) -> Card {
+    CardCodeAndCount::from_data(code, 1).unwrap().card().clone()
+}
+
+fn deck(cards: &[(&str, i32)]) -> Deck {
+    cards.iter().collect()
+}
+
+#[test]
+fn lineup_validation() {
+    let champions: HashSet<Card> = [card("01SI053"), card("01FR024")].into_iter().collect();
+    let rules = LineupRules::new(3, champions);
+
+    let valid = Lineup::new(vec![
+        deck(&[("01SI053", 3), ("01PZ001", 3)]),
+        deck(&[("01FR024", 3), ("01DE001", 3)]),
+        deck(&[("01IO001", 3), ("01NX001", 3)]),
+    ]);
+    assert!(valid.validate(&rules).is_ok());
+
+    let invalid = Lineup::new(vec![
+        deck(&[("01SI053", 3), ("01PZ001", 3)]),
+        deck(&[("01SI053", 3), ("01DE001", 3)]),
+    ]);
+    let violations = invalid.validate(&rules).unwrap_err();
+    assert_eq!(
+        violations,
+        vec![
+            LineupViolation::DeckCount {
+                expected: 3,
+                found: 2
+            },
+            LineupViolation::SharedRegion {
+                region: String::from("SI"),
+                decks: (0, 1)
+            },
+            LineupViolation::SharedChampion {
+                champion: card("01SI053"),
+                decks: (0, 1)
+            },
+        ]
+    );
+    assert_eq!(
+        violations[2].to_string(),
+        "decks 0 and 1 share champion 01SI053"
+    );
+
+    let relaxed = LineupRules {
+        unique_regions: false,
+        ..LineupRules::new(2, HashSet::new())
+    };
+    assert!(invalid.validate(&relaxed).is_ok());
+}
+
+#[test]
+fn lineup_code_round_trip() {
+    let lineup = Lineup::new(vec![
+        deck(&[("01SI053", 3), ("01PZ001", 3)]),
+        deck(&[("01FR024", 3), ("01DE001", 3)]),
+        deck(&[("01IO001", 3), ("01NX001", 3)]),
+    ]);
+
+    let code = lineup.to_code().unwrap();
+    assert_eq!(code.split('.').count(), 3);
+
+    let decoded = Lineup::from_code(&code).unwrap();
+    for (deck, decoded) in lineup.decks().iter().zip(decoded.decks()) {
+        let mut expected = deck.cards().clone();
+        let mut actual = decoded.cards().clone();
+        expected.sort();
+        actual.sort();
+        assert_eq!(expected, actual);
+    }
+
+    assert!(Lineup::from_code("").unwrap().decks().is_empty());
+    assert!(Lineup::from_code(&format!("{code}.")).is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn lineup_serde() {
+    let lineup = Lineup::new(vec![
+        deck(&[("01SI053", 3), ("01PZ001", 3)]),
+        deck(&[("01FR024", 3), ("01DE001", 3)]),
+    ]);
+
+    let json = serde_json::to_string(&lineup).unwrap();
+    assert_eq!(serde_json::from_str::<Lineup>(&json).unwrap(), lineup);
+}
@@ -0,0 +1,112 @@
+#![cfg(feature = "std")]
+
+use lordeckcodes::meta::MetaReport;
+use lordeckcodes::{CardCodeAndCount, Deck};
+
+fn deck(cards: &[(&str, i32)]) -> Deck {
+    cards.iter().collect()
+}
+
+#[test]
+fn meta_report_statistics() {
+    let champions = vec![
+        CardCodeAndCount::from_data("01SI053", 1)
+            .unwrap()
+            .card()
+            .clone(),
+        CardCodeAndCount::from_data("01FR024", 1)
+            .unwrap()
+            .card()
+            .clone(),
+    ];
+
+    let mut builder = MetaReport::builder();
+    builder.champions(champions);
+    builder.add_with_record(
+        &deck(&[("01SI053", 3), ("01FR024", 2), ("01FR003", 3)]),
+        6,
+        4,
+    );
+    builder.add_with_record(&deck(&[("01SI053", 1), ("01SI015", 3)]), 2, 8);
+    builder.add(&deck(&[("01FR003", 2), ("01FR024", 3)]));
+    builder.add(&deck(&[("01FR003", 3), ("01FR024", 3)]));
+    let report = builder.build();
+
+    assert_eq!(report.decks(), 4);
+
+    let card = &report.cards()[0];
+    assert_eq!(card.card(), "01FR003");
+    assert_eq!(card.decks(), 3);
+    assert_eq!(card.play_rate(), 0.75);
+    assert!((card.average_copies() - 8.0 / 3.0).abs() < 1e-9);
+    assert_eq!(card.win_rate().unwrap().wins(), 6);
+
+    let regions = report.regions();
+    assert_eq!(regions.len(), 3);
+    assert_eq!(regions[0].key(), &["FR"]);
+    assert_eq!(regions[0].decks(), 2);
+    assert!(regions[0].win_rate().is_none());
+
+    let champions = report.champions();
+    assert_eq!(champions[0].key(), &["01FR024"]);
+    assert_eq!(champions[1].key(), &["01FR024", "01SI053"]);
+
+    let win_rate = champions[2].win_rate().unwrap();
+    assert_eq!(champions[2].key(), &["01SI053"]);
+    assert_eq!(win_rate.rate(), 0.2);
+    assert!(win_rate.lower() > 0.05 && win_rate.lower() < 0.2);
+    assert!(win_rate.upper() > 0.2 && win_rate.upper() < 0.55);
+}
+
+#[test]
+fn meta_report_champions_apply_to_earlier_decks() {
+    let champion = CardCodeAndCount::from_data("01SI053", 1)
+        .unwrap()
+        .card()
+        .clone();
+
+    let mut builder = MetaReport::builder();
+    builder
+        .add_with_record(&deck(&[("01SI053", 3), ("01SI015", 3)]), 1, 0)
+        .add(&deck(&[("01SI015", 3)]));
+    builder.champions(vec![champion]);
+    builder.add_with_record(&deck(&[("01SI015", 2), ("01SI053", 1)]), 0, 1);
+    let report = builder.build();
+
+    let champions = report.champions();
+    assert_eq!(champions.len(), 2);
+    assert_eq!(champions[0].key(), &["01SI053"]);
+    assert_eq!(champions[0].decks(), 2);
+    assert_eq!(champions[0].win_rate().unwrap().rate(), 0.5);
+    assert!(champions[1].key().is_empty());
+
+    builder.champions(vec![]);
+    let report = builder.build();
+    assert_eq!(report.champions().len(), 1);
+    assert_eq!(report.champions()[0].decks(), 3);
+}
+
+#[test]
+fn meta_report_large_records() {
+    let mut builder = MetaReport::builder();
+    builder
+        .add_with_record(&deck(&[("01SI015", 3)]), u32::MAX, 1)
+        .add_with_record(&deck(&[("01SI015", 3)]), u32::MAX, u32::MAX);
+    let report = builder.build();
+
+    let win_rate = report.cards()[0].win_rate().unwrap();
+    assert_eq!(win_rate.wins(), u32::MAX);
+    assert_eq!(win_rate.losses(), u32::MAX);
+    assert_eq!(win_rate.rate(), 0.5);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn meta_report_serialize() {
+    let mut builder = MetaReport::builder();
+    builder.add(&deck(&[("01SI015", 3)]));
+
+    let json = serde_json::to_value(builder.build()).unwrap();
+    assert_eq!(json["cards"][0]["card"], "01SI015");
+    assert_eq!(json["regions"][0]["key"][0], "SI");
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "proptest")]
+
+use proptest::prelude::*;
+
+use lordeckcodes::{encoder, strategy, Card, CardCodeAndCount, Deck};
+
+fn sorted(deck: &Deck) -> Vec<CardCodeAndCount> {
+    let mut cards = deck.cards().clone();
+    cards.sort();
+    cards
+}
+
+proptest! {
+    #[test]
+    fn deck_round_trip(deck in strategy::deck()) {
+        let code = encoder::code_from_deck(&deck).unwrap();
+        let decoded = encoder::deck_from_code(&code).unwrap();
+        prop_assert_eq!(sorted(&decoded), sorted(&deck));
+        prop_assert_eq!(encoder::normalize_code(&code).unwrap(), code);
+    }
+
+    #[test]
+    fn decode_into_matches_deck_from_code(deck in any::<Deck>()) {
+        let code = encoder::code_from_deck(&deck).unwrap();
+        let mut buffer = vec![None; deck.cards().len()];
+        let len = encoder::decode_into(&code, &mut buffer).unwrap();
+        let decoded = encoder::deck_from_code(&code).unwrap();
+        prop_assert_eq!(len, buffer.len());
+        prop_assert!(buffer.iter().flatten().eq(decoded.cards()));
+    }
+
+    #[test]
+    fn card_code_round_trip(card in any::<Card>(), count in strategy::count()) {
+        let card_count = CardCodeAndCount::from_data(&card.to_string(), count).unwrap();
+        prop_assert_eq!(card_count.card(), &card);
+        prop_assert_eq!(card_count.count(), count);
+    }
+
+    #[test]
+    fn cards_up_to_version(card in strategy::card_up_to_version(1)) {
+        let faction = card.faction_code().unwrap();
+        prop_assert!(["DE", "FR", "IO", "NX", "PZ", "SI"].contains(&faction));
+    }
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "python")]
+
+use std::ffi::CStr;
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::wrap_pymodule;
+
+/// Runs a Python snippet with the module imported as `lordeckcodes`.
+fn run(code: &CStr) {
+    Python::initialize();
+    Python::attach(|py| {
+        let locals = PyDict::new(py);
+        locals
+            .set_item(
+                "lordeckcodes",
+                wrap_pymodule!(lordeckcodes::python::lordeckcodes)(py),
+            )
+            .unwrap();
+
+        if let Err(e) = py.run(code, None, Some(&locals)) {
+            e.print(py);
+            panic!("python error");
+        }
+    });
+}
+
+#[test]
+fn decode_encode() {
+    run(c"
+code = 'CEBAIAIFB4WDANQIAEAQGDAUDAQSIJZUAIAQCAIEAEAQKBIA'
+cards = lordeckcodes.decode(code)
+assert cards[0] == ('01SI015', 3)
+assert len(cards) == 14
+assert lordeckcodes.encode(cards) == code
+
+assert lordeckcodes.decode_batch([code, 'nope']) == [cards, None]
+assert lordeckcodes.encode_batch([cards, [('01SI015', 0)]]) == [code, None]
+
+try:
+    lordeckcodes.decode('nope')
+    raise AssertionError('no error')
+except ValueError as e:
+    assert isinstance(e, lordeckcodes.DeckCodeError)
+");
+}
+
+#[test]
+fn deck_class() {
+    run(c"
+deck = lordeckcodes.Deck([('01SI015', 2)])
+deck.add('01SI015')
+deck.add('01FR003', 3)
+assert deck.count('01SI015') == 3
+assert len(deck) == 6
+assert deck.regions == ['FR', 'SI']
+assert deck.region_counts == {'FR': 3, 'SI': 3}
+
+deck.remove('01FR003', 1)
+assert deck.count('01FR003') == 2
+deck.remove('01FR003')
+assert '01FR003' not in deck
+assert deck.cards == [('01SI015', 3)]
+
+try:
+    deck.remove('01FR003')
+    raise AssertionError('no error')
+except KeyError:
+    pass
+
+for method, count in [(deck.remove, -1), (deck.remove, 4), (deck.add, 0), (deck.add, 2**31 - 1)]:
+    try:
+        method('01SI015', count)
+        raise AssertionError('no error')
+    except ValueError:
+        pass
+assert deck.count('01SI015') == 3
+
+assert lordeckcodes.Deck.from_code(deck.to_code()) == deck
+
+large = lordeckcodes.Deck([('01SI015', 2**31 - 1), ('01FR003', 2**31 - 1)])
+assert len(large) == 2**32 - 2
+
+# cards of factions unknown to this version of the library are kept
+unknown = lordeckcodes.Deck.from_code('CEAQCAIIB4AAA')
+assert len(unknown) == 3
+assert unknown.regions == ['8']
+assert lordeckcodes.Deck.from_code(unknown.to_code()) == unknown
+");
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "qr")]
+
+use lordeckcodes::qr;
+use qrcode::Version;
+
+const CODE: &str = "CEBAIAIFB4WDANQIAEAQGDAUDAQSIJZUAIAQCAIEAEAQKBIA";
+
+#[test]
+fn qr_uses_alphanumeric_mode() {
+    // 48 characters fit version 3-M in alphanumeric mode, but would need version 4 as bytes
+    assert_eq!(qr::qr_code(CODE).unwrap().version(), Version::Normal(3));
+    assert_eq!(
+        qr::qr_code(&CODE.to_lowercase()).unwrap().to_colors(),
+        qr::qr_code(CODE).unwrap().to_colors()
+    );
+    assert!(matches!(
+        qr::qr_code("not a code!"),
+        Err(qr::QrCodeError::Encode(_))
+    ));
+}
+
+#[test]
+fn qr_svg_and_png() {
+    let svg = qr::to_svg(CODE, 4).unwrap();
+    assert!(svg.contains("<svg"));
+
+    let png = qr::to_png(CODE, 4).unwrap();
+    assert_eq!(&png[1..4], b"PNG");
+    // (29 modules + 2 * 4 quiet zone modules) * 4 pixels
+    assert_eq!(&png[16..24], &[0, 0, 0, 148, 0, 0, 0, 148]);
+}
+
+#[test]
+fn qr_png_too_large() {
+    // 37 modules a side, so 221 pixel modules are the largest that fit
+    assert!(qr::to_png(CODE, 221).is_ok());
+    for module_size in [222, u32::MAX] {
+        assert!(matches!(
+            qr::to_png(CODE, module_size),
+            Err(qr::QrCodeError::ImageTooLarge)
+        ));
+    }
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "render")]
+
+use lordeckcodes::database::{CardDatabase, CardInfo};
+use lordeckcodes::{render, Deck};
+
+#[test]
+fn render_svg() {
+    let database: CardDatabase = vec![
+        CardInfo::new("01SI053", "Elise", 2, true).unwrap(),
+        CardInfo::new("01SI005", "Vile Feast", 1, false).unwrap(),
+        CardInfo::new("01FR024", "Anivia & <Friends>", 7, true).unwrap(),
+        CardInfo::new("01FR036", "Avalanche", 9, false).unwrap(),
+    ]
+    .into_iter()
+    .collect();
+    let deck: Deck = [
+        ("01SI053", 3),
+        ("01SI005", 2),
+        ("01FR024", 2),
+        ("01FR036", 1),
+    ]
+    .iter()
+    .collect();
+
+    let svg = render::svg(&deck, &database);
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+    assert!(svg.ends_with("</svg>\n"));
+    assert_eq!(svg.matches("class=\"region\"").count(), 2);
+    assert_eq!(svg.matches("class=\"card\"").count(), 4);
+    assert!(svg.contains("<title>Freljord</title>"));
+    assert!(svg.contains("Anivia &amp; &lt;Friends&gt;"));
+    assert!(svg.contains(">7+</text>"));
+    assert!(svg.contains(">x3</text>"));
+}
+
+#[test]
+fn render_svg_huge_counts() {
+    let database: CardDatabase = vec![
+        CardInfo::new("01SI053", "Elise", 2, true).unwrap(),
+        CardInfo::new("01SI005", "Vile Feast", 2, false).unwrap(),
+    ]
+    .into_iter()
+    .collect();
+    let deck: Deck = [("01SI053", i32::MAX), ("01SI005", i32::MAX)]
+        .iter()
+        .collect();
+
+    let svg = render::svg(&deck, &database);
+    assert!(svg.contains(&format!(">{}</text>", 2 * i64::from(i32::MAX))));
+    assert!(svg.contains(&format!(">x{}</text>", i32::MAX)));
+}
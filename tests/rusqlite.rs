@@ -0,0 +1,57 @@
+#![cfg(feature = "rusqlite")]
+
+use rusqlite::Connection;
+
+use lordeckcodes::{encoder, Card, Deck};
+
+const CODE: &str = "CEBAIAIFB4WDANQIAEAQGDAUDAQSIJZUAIAQCAIEAEAQKBIA";
+
+#[test]
+fn sqlite_round_trip() {
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute(
+        "CREATE TABLE decks (deck TEXT NOT NULL, featured TEXT NOT NULL)",
+        [],
+    )
+    .unwrap();
+
+    let deck = encoder::deck_from_code(CODE).unwrap();
+    let card = deck.cards()[0].card().clone();
+    conn.execute("INSERT INTO decks VALUES (?1, ?2)", (&deck, &card))
+        .unwrap();
+
+    let (raw_deck, raw_card): (String, String) = conn
+        .query_row("SELECT deck, featured FROM decks", [], |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })
+        .unwrap();
+    assert_eq!(raw_deck, CODE);
+    assert_eq!(raw_card, "01SI015");
+
+    let (stored_deck, stored_card): (Deck, Card) = conn
+        .query_row("SELECT deck, featured FROM decks", [], |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })
+        .unwrap();
+    assert_eq!(stored_deck, deck);
+    assert_eq!(stored_card, card);
+
+    let invalid = conn.query_row("SELECT 'not a code'", [], |row| row.get::<_, Deck>(0));
+    assert!(invalid.is_err());
+    let invalid = conn.query_row("SELECT '01XX015'", [], |row| row.get::<_, Card>(0));
+    assert!(invalid.is_err());
+}
+
+#[test]
+fn sqlite_rejects_cards_without_code() {
+    let conn = Connection::open_in_memory().unwrap();
+
+    // a deck with a card of faction 8, unknown to this version of the library
+    let unknown = encoder::deck_from_code("CEAQCAIIB4AAA").unwrap();
+    let card = unknown.cards()[0].card().clone();
+    let result = conn.query_row("SELECT ?1", [&card], |row| row.get::<_, String>(0));
+    assert!(matches!(
+        result,
+        Err(rusqlite::Error::ToSqlConversionFailure(_))
+    ));
+}
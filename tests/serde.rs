@@ -0,0 +1,114 @@
+#![cfg(feature = "serde")]
+
+use serde::{Deserialize, Serialize};
+
+use lordeckcodes::{encoder, Card, Deck};
+
+const CODE: &str = "CEBAIAIFB4WDANQIAEAQGDAUDAQSIJZUAIAQCAIEAEAQKBIA";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Compact {
+    #[serde(with = "lordeckcodes::serde::as_code")]
+    deck: Deck,
+    #[serde(with = "lordeckcodes::serde::as_code")]
+    card: Card,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Readable {
+    #[serde(with = "lordeckcodes::serde::as_map")]
+    deck: Deck,
+}
+
+#[test]
+fn as_code() {
+    let compact = Compact {
+        deck: encoder::deck_from_code(CODE).unwrap(),
+        card: encoder::deck_from_code(CODE).unwrap().cards()[0]
+            .card()
+            .clone(),
+    };
+
+    let json = serde_json::to_string(&compact).unwrap();
+    assert_eq!(json, format!(r#"{{"deck":"{CODE}","card":"01SI015"}}"#));
+
+    let decoded: Compact = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded.deck, compact.deck);
+    assert_eq!(decoded.card, compact.card);
+
+    assert!(serde_json::from_str::<Compact>(r#"{"deck":"nope","card":"01SI015"}"#).is_err());
+    assert!(
+        serde_json::from_str::<Compact>(&format!(r#"{{"deck":"{CODE}","card":"01XX015"}}"#))
+            .is_err()
+    );
+}
+
+#[test]
+fn as_map() {
+    let deck: Deck = [("01SI015", 2), ("01FR003", 3), ("01SI015", 1)]
+        .iter()
+        .collect();
+    let json = serde_json::to_string(&Readable { deck }).unwrap();
+    assert_eq!(json, r#"{"deck":{"01SI015":3,"01FR003":3}}"#);
+
+    let readable: Readable = serde_json::from_str(&json).unwrap();
+    let expected: Deck = [("01SI015", 3), ("01FR003", 3)].iter().collect();
+    assert_eq!(readable.deck, expected);
+
+    for invalid in [
+        r#"{"deck":{"01SI015":0}}"#,
+        r#"{"deck":{"01SI01":1}}"#,
+        r#"{"deck":{"01SI015":1,"01SI015":2}}"#,
+    ] {
+        assert!(
+            serde_json::from_str::<Readable>(invalid).is_err(),
+            "{invalid}"
+        );
+    }
+}
+
+#[test]
+fn validated_deserialization() {
+    let deck: Deck =
+        serde_json::from_str(r#"[{"card":{"set":1,"faction":5,"number":15},"count":3}]"#).unwrap();
+    let expected: Deck = [("01SI015", 3)].iter().collect();
+    assert_eq!(deck, expected);
+
+    for invalid in [
+        r#"[{"card":{"set":1,"faction":5,"number":15},"count":0}]"#,
+        r#"[{"card":{"set":1,"faction":8,"number":15},"count":3}]"#,
+        r#"[{"card":{"set":100,"faction":5,"number":15},"count":3}]"#,
+        r#"[{"card":{"set":1,"faction":5,"number":1000},"count":3}]"#,
+    ] {
+        assert!(serde_json::from_str::<Deck>(invalid).is_err(), "{invalid}");
+    }
+}
+
+#[test]
+fn unknown_faction_cards() {
+    // a deck with a card of faction 8, unknown to this version of the library
+    let unknown = encoder::deck_from_code("CEAQCAIIB4AAA").unwrap();
+    let compact = Compact {
+        deck: unknown.clone(),
+        card: unknown.cards()[0].card().clone(),
+    };
+    assert!(serde_json::to_string(&compact).is_err());
+    assert!(serde_json::to_string(&Readable { deck: unknown }).is_err());
+
+    // every card with a card code round trips
+    for card_count in encoder::deck_from_code(CODE).unwrap().cards() {
+        let compact = Compact {
+            deck: encoder::deck_from_code(CODE).unwrap(),
+            card: card_count.card().clone(),
+        };
+        let json = serde_json::to_string(&compact).unwrap();
+        let decoded: Compact = serde_json::from_str(&json).unwrap();
+        assert_eq!(&decoded.card, card_count.card());
+    }
+}
+
+#[test]
+fn as_map_too_many_copies() {
+    let deck: Deck = [("01SI015", i32::MAX), ("01SI015", 1)].iter().collect();
+    assert!(serde_json::to_string(&Readable { deck }).is_err());
+}
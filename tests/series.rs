@@ -0,0 +1,110 @@
+#![cfg(feature = "std")]
+
+use lordeckcodes::lineup::Lineup;
+use lordeckcodes::series::{
+    analyze_bans, series_win_probability, MatchupTable, SeriesFormat, MAX_SERIES_DECKS,
+};
+use lordeckcodes::Deck;
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+#[test]
+fn series_probabilities() {
+    let mut table = MatchupTable::new();
+    table.insert("A", "X", 0.6);
+    table.insert("A", "Y", 0.5);
+    table.insert("B", "X", 0.4);
+    table.insert("B", "Y", 0.7);
+
+    // even matchups give even series in both formats
+    let even = MatchupTable::new();
+    for format in [SeriesFormat::Conquest, SeriesFormat::LastDeckStanding] {
+        assert!(close(
+            series_win_probability(format, &["A", "B"], &["X", "Y"], &even).unwrap(),
+            0.5
+        ));
+    }
+
+    // conquest with one deck each needs a single win
+    assert!(close(
+        series_win_probability(SeriesFormat::Conquest, &["B"], &["Y"], &table).unwrap(),
+        0.7
+    ));
+
+    // last deck standing: A then B against a single deck X
+    let p = series_win_probability(SeriesFormat::LastDeckStanding, &["A", "B"], &["X"], &table)
+        .unwrap();
+    assert!(close(p, 0.6 + 0.4 * 0.4));
+
+    assert_eq!(
+        series_win_probability::<&str>(SeriesFormat::Conquest, &[], &["X"], &table),
+        Some(0.0)
+    );
+}
+
+#[test]
+fn series_mirrors_and_large_lineups() {
+    let mut table = MatchupTable::new();
+    table.insert("A", "X", 0.6);
+    table.insert("A", "A", 0.8);
+    assert_eq!(table.win_rate("A", "A"), 0.5);
+
+    let player = ["A"; MAX_SERIES_DECKS];
+    let opponent = ["X"; MAX_SERIES_DECKS];
+    for format in [SeriesFormat::Conquest, SeriesFormat::LastDeckStanding] {
+        let p = series_win_probability(format, &player, &opponent, &table).unwrap();
+        assert!(p > 0.6 && p < 1.0);
+    }
+
+    let too_many = ["A"; MAX_SERIES_DECKS + 1];
+    assert_eq!(
+        series_win_probability(SeriesFormat::Conquest, &too_many, &opponent, &table),
+        None
+    );
+    assert_eq!(
+        series_win_probability(SeriesFormat::Conquest, &["A"; 40], &["X"], &table),
+        None
+    );
+}
+
+#[test]
+fn series_bans() {
+    let lineup = |codes: &[&str]| -> Lineup {
+        Lineup::new(
+            codes
+                .iter()
+                .map(|code| [(*code, 3)].iter().collect())
+                .collect(),
+        )
+    };
+    let archetype = |deck: &Deck| deck.cards()[0].card().to_string();
+
+    let player = lineup(&["01SI001", "01FR001", "01DE001"]);
+    let opponent = lineup(&["01IO001", "01NX001", "01PZ001"]);
+
+    let mut table = MatchupTable::new();
+    for player_deck in ["01SI001", "01FR001", "01DE001"] {
+        table.insert(player_deck, "01NX001", 0.2);
+    }
+    table.insert("01DE001", "01IO001", 0.9);
+    table.insert("01DE001", "01PZ001", 0.9);
+
+    for format in [SeriesFormat::Conquest, SeriesFormat::LastDeckStanding] {
+        let bans = analyze_bans(format, &player, &opponent, &table, archetype).unwrap();
+        assert_eq!(bans.ban(), 1);
+        assert_eq!(bans.opponent_ban(), 2);
+        assert_eq!(bans.matrix().len(), 3);
+        assert!(close(bans.win_probability(), bans.matrix()[1][2]));
+    }
+
+    assert!(analyze_bans(
+        SeriesFormat::Conquest,
+        &lineup(&["01SI001"]),
+        &opponent,
+        &table,
+        archetype
+    )
+    .is_none());
+}
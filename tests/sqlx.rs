@@ -0,0 +1,57 @@
+#![cfg(feature = "sqlx")]
+
+use sqlx::sqlite::SqlitePool;
+
+use lordeckcodes::{encoder, Card, Deck};
+
+const CODE: &str = "CEBAIAIFB4WDANQIAEAQGDAUDAQSIJZUAIAQCAIEAEAQKBIA";
+
+#[tokio::test]
+async fn sqlite_round_trip() {
+    let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+    sqlx::query("CREATE TABLE decks (deck TEXT NOT NULL, featured TEXT NOT NULL)")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let deck = encoder::deck_from_code(CODE).unwrap();
+    let card = deck.cards()[0].card().clone();
+    sqlx::query("INSERT INTO decks VALUES (?, ?)")
+        .bind(&deck)
+        .bind(&card)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let (raw_deck, raw_card): (String, String) = sqlx::query_as("SELECT deck, featured FROM decks")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(raw_deck, CODE);
+    assert_eq!(raw_card, "01SI015");
+
+    let (stored_deck, stored_card): (Deck, Card) =
+        sqlx::query_as("SELECT deck, featured FROM decks")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(stored_deck, deck);
+    assert_eq!(stored_card, card);
+
+    let invalid: Result<(Deck,), _> = sqlx::query_as("SELECT 'not a code'").fetch_one(&pool).await;
+    assert!(invalid.is_err());
+}
+
+#[tokio::test]
+async fn sqlite_rejects_cards_without_code() {
+    let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+
+    // a deck with a card of faction 8, unknown to this version of the library
+    let unknown = encoder::deck_from_code("CEAQCAIIB4AAA").unwrap();
+    let card = unknown.cards()[0].card().clone();
+    let result: Result<(String,), _> = sqlx::query_as("SELECT ?")
+        .bind(&card)
+        .fetch_one(&pool)
+        .await;
+    assert!(matches!(result, Err(sqlx::Error::Encode(_))));
+}
@@ -0,0 +1,194 @@
+#![cfg(feature = "store")]
+
+use lordeckcodes::store::{DeckQuery, DeckStore, StoreError};
+use lordeckcodes::{encoder, Card, CardCodeAndCount, Deck};
+
+fn card(code: &str) -> Card {
+    CardCodeAndCount::from_data(code, 1).unwrap().card().clone()
+}
+
+fn code(cards: &[(&str, i32)]) -> String {
+    encoder::code_from_deck(&cards.iter().collect()).unwrap()
+}
+
+fn store() -> DeckStore {
+    let mut store = DeckStore::open_in_memory().unwrap();
+    store
+        .set_champions([card("01SI042"), card("01FR024")])
+        .unwrap();
+    store
+}
+
+#[test]
+fn save_is_keyed_by_normalized_code() {
+    let mut store = store();
+    let cards = [("01SI042", 3), ("01SI015", 3), ("01FR024", 2)];
+    let id = store.save("Elise", &code(&cards)).unwrap();
+
+    // the same cards in another order, written in lowercase
+    let reordered = code(&[("01FR024", 2), ("01SI015", 3), ("01SI042", 3)]);
+    assert_eq!(store.save("Copy", &reordered.to_lowercase()).unwrap(), id);
+
+    let stored = store.find(&reordered).unwrap().unwrap();
+    assert_eq!(stored.id(), id);
+    assert_eq!(stored.name(), "Elise");
+    assert_eq!(stored.code(), encoder::normalize_code(&reordered).unwrap());
+    assert_eq!(stored.champions(), &[card("01FR024"), card("01SI042")]);
+    assert_eq!(store.search(&DeckQuery::new()).unwrap().len(), 1);
+
+    assert!(store.save("Broken", "not a deck code").is_err());
+}
+
+#[test]
+fn edit_metadata() {
+    let mut store = store();
+    let id = store.save("Spiders", &code(&[("01SI042", 3)])).unwrap();
+
+    store.rename(id, "Elise Spiders").unwrap();
+    store
+        .set_notes(id, "mulligan for Frenzied Skitterer")
+        .unwrap();
+    store.add_tag(id, "ladder").unwrap();
+    store.add_tag(id, "aggro").unwrap();
+    store.add_tag(id, "ladder").unwrap();
+    assert!(store.remove_tag(id, "aggro").unwrap());
+    assert!(!store.remove_tag(id, "aggro").unwrap());
+
+    let stored = store.get(id).unwrap().unwrap();
+    assert_eq!(stored.name(), "Elise Spiders");
+    assert_eq!(stored.notes(), "mulligan for Frenzied Skitterer");
+    assert_eq!(stored.tags(), &["ladder".to_string()]);
+
+    assert!(store.delete(id).unwrap());
+    assert!(store.get(id).unwrap().is_none());
+    assert!(matches!(
+        store.rename(id, "Gone"),
+        Err(StoreError::DeckNotFound)
+    ));
+    assert!(matches!(
+        store.add_tag(id, "gone"),
+        Err(StoreError::DeckNotFound)
+    ));
+}
+
+#[test]
+fn search() {
+    let mut store = store();
+    let elise = store
+        .save("Elise", &code(&[("01SI042", 3), ("01SI015", 3)]))
+        .unwrap();
+    let ashe = store
+        .save("Ashe Noxus", &code(&[("01FR024", 3), ("01NX020", 3)]))
+        .unwrap();
+    let combo = store
+        .save(
+            "Ashe Elise",
+            &code(&[("01FR024", 2), ("01SI042", 2), ("01SI015", 1)]),
+        )
+        .unwrap();
+    store.add_tag(ashe, "tournament").unwrap();
+    store.add_tag(combo, "tournament").unwrap();
+
+    let ids = |query: DeckQuery| -> Vec<_> {
+        store
+            .search(&query)
+            .unwrap()
+            .iter()
+            .map(|stored| stored.id())
+            .collect()
+    };
+
+    assert_eq!(ids(DeckQuery::new()), [combo, ashe, elise]);
+    assert_eq!(ids(DeckQuery::new().card(card("01SI015"))), [combo, elise]);
+    assert_eq!(ids(DeckQuery::new().region("SI").region("FR")), [combo]);
+    assert_eq!(ids(DeckQuery::new().region("NX")), [ashe]);
+    assert_eq!(
+        ids(DeckQuery::new().champion(card("01FR024"))),
+        [combo, ashe]
+    );
+    // cards that are not champions are rejected
+    assert!(matches!(
+        store.search(&DeckQuery::new().champion(card("01SI015"))),
+        Err(StoreError::UnknownChampion(champion)) if champion == card("01SI015")
+    ));
+    assert_eq!(
+        ids(DeckQuery::new().tag("tournament").champion(card("01SI042"))),
+        [combo]
+    );
+    assert_eq!(ids(DeckQuery::new().name("elise")), [combo, elise]);
+}
+
+#[test]
+fn history() {
+    let mut store = store();
+    let first = code(&[("01SI042", 3), ("01SI015", 3)]);
+    let second = code(&[("01SI042", 3), ("01SI015", 1), ("01SI044", 2)]);
+    let id = store.save("Elise", &first).unwrap();
+
+    store.update(id, &second).unwrap();
+    // saving the current code again is not a new version
+    store.update(id, &second).unwrap();
+
+    let stored = store.get(id).unwrap().unwrap();
+    assert_eq!(stored.code(), encoder::normalize_code(&second).unwrap());
+    assert_eq!(store.find(&first).unwrap(), None);
+    assert_eq!(
+        store
+            .search(&DeckQuery::new().card(card("01SI044")))
+            .unwrap()
+            .len(),
+        1
+    );
+
+    let history = store.history(id).unwrap();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].version(), 1);
+    assert!(history[0].changes().is_empty());
+    assert_eq!(history[1].version(), 2);
+    assert_eq!(history[1].code(), stored.code());
+
+    let expected = encoder::deck_from_code(&first)
+        .unwrap()
+        .diff(&encoder::deck_from_code(&second).unwrap());
+    assert_eq!(history[1].changes(), &expected);
+    let added: Deck = [("01SI044", 2)].iter().collect();
+    let removed: Deck = [("01SI015", 2)].iter().collect();
+    assert_eq!(expected.added(), added.cards().as_slice());
+    assert_eq!(expected.removed(), removed.cards().as_slice());
+
+    let deck_history = store.deck_history(id).unwrap();
+    assert_eq!(deck_history.name(), "Elise");
+    assert_eq!(deck_history.len(), 2);
+    assert_eq!(deck_history.diffs().nth(1), Some(expected));
+    assert_eq!(deck_history.latest().unwrap().code(), stored.code());
+
+    // another deck cannot take a code that is already saved
+    let other = store.save("Other", &code(&[("01FR024", 3)])).unwrap();
+    assert!(store.update(other, &second).is_err());
+}
+
+#[test]
+fn persists_to_file() {
+    let path = std::env::temp_dir().join(format!("lordeckcodes-store-{}.db", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let id = {
+        let mut store = DeckStore::open(&path).unwrap();
+        store.set_champions([card("01SI042")]).unwrap();
+        store.save("Elise", &code(&[("01SI042", 3)])).unwrap()
+    };
+    let store = DeckStore::open(&path).unwrap();
+    let stored = store.get(id).unwrap().unwrap();
+    assert_eq!(stored.name(), "Elise");
+    assert_eq!(stored.champions(), &[card("01SI042")]);
+    assert_eq!(
+        store
+            .search(&DeckQuery::new().champion(card("01SI042")))
+            .unwrap()
+            .len(),
+        1
+    );
+
+    drop(store);
+    std::fs::remove_file(&path).unwrap();
+}
@@ -4,6 +4,7 @@ use std::io::{BufRead, BufReader};
 
 use std::collections::HashMap;
 
+#[cfg(feature = "serde")]
 use serde_json::Error;
 
 use lordeckcodes::encoder::deck_from_code;
@@ -53,15 +54,8 @@ fn encode_decode_recommended() -> Result<(), Box<dyn std::error::Error>> {
     let mut codes = vec![];
     let mut decks = vec![];
 
-    loop {
-        match f.by_ref().lines().next() {
-            Some(code) => {
-                codes.push(code?);
-            }
-            None => {
-                break;
-            }
-        }
+    while let Some(code) = f.by_ref().lines().next() {
+        codes.push(code?);
 
         let deck = f
             .by_ref()
@@ -353,15 +347,44 @@ fn bad_card_codes() {
     assert!(CardCodeAndCount::from_data("01DE002", 0).is_err());
 }
 
+// Found by the fuzz targets: slicing the code panicked on short or non-ASCII input.
+#[test]
+fn bad_card_code_slices() {
+    for code in [
+        "",
+        "01",
+        "01SI",
+        "01S\u{e9}015",
+        "\u{e9}\u{e9}SI015",
+        "01SI\u{e9}1",
+        "+1SI015",
+    ] {
+        assert!(
+            matches!(
+                CardCodeAndCount::from_data(code, 1),
+                Err(LorError::InvalidCard)
+            ),
+            "{code}"
+        );
+        assert!(
+            matches!(
+                Deck::new().add_from_data(code, 1),
+                Err(LorError::InvalidCard)
+            ),
+            "{code}"
+        );
+    }
+}
+
 #[test]
 fn bad_count() {
     assert!(CardCodeAndCount::from_data("01DE002", 0).is_err());
     assert!(CardCodeAndCount::from_data("01DE002", -1).is_err());
 
-    match CardCodeAndCount::from_data("01DE002", -1) {
-        Err(LorError::InvalidCard) => assert!(true),
-        _ => assert!(false),
-    }
+    assert!(matches!(
+        CardCodeAndCount::from_data("01DE002", -1),
+        Err(LorError::InvalidCard)
+    ));
 }
 
 #[test]
@@ -374,12 +397,85 @@ fn garbage_decoding() {
     assert!(encoder::deck_from_code(&bad_encoding32).is_err());
     assert!(encoder::deck_from_code(&bad_encoding_empty).is_err());
 
-    match encoder::deck_from_code(&bad_encoding_empty) {
-        Err(LorError::InvalidCard) => assert!(true),
-        _ => assert!(false),
+    assert!(matches!(
+        encoder::deck_from_code(&bad_encoding_empty),
+        Err(LorError::InvalidCard)
+    ));
+}
+
+// Found by the fuzz targets: these decoded to counts that could not be encoded again.
+#[test]
+fn bad_count_decoding() {
+    for code in [
+        "CEBAIAIABEQDINIFAEBAUEATEEYAAAIBAIYQGAAIBAIYQGAIAAIA",
+        "AAAAAAAAAAABIEQUCYQSOLYAAEAQCAIX",
+    ] {
+        assert!(
+            matches!(encoder::deck_from_code(code), Err(LorError::InvalidDeck)),
+            "{code}"
+        );
+    }
+}
+
+#[test]
+fn decode_errors_match_base32() {
+    for code in [
+        "I'm no card code!",
+        "ABCDEFG",
+        "CEBAIAIFB",
+        "CEBAIAIFB4WDANQIAEAQGDAUDAQSIJZUAIAQCAIEAEAQKBJ",
+        "cebaiaifb4wdanqiaeaqgdaudaqsijzuaiaqcaieaeaqkbia",
+        "CEBAIAIF=",
+    ] {
+        let expected = data_encoding::BASE32_NOPAD
+            .decode(code.as_bytes())
+            .unwrap_err();
+        assert!(
+            matches!(encoder::deck_from_code(code), Err(LorError::Decode(e)) if e == expected),
+            "{code}"
+        );
     }
 }
 
+#[test]
+fn decode_with_and_decode_into() {
+    let code = "CEBAIAIFB4WDANQIAEAQGDAUDAQSIJZUAIAQCAIEAEAQKBIA";
+    let deck = encoder::deck_from_code(code).unwrap();
+
+    let mut visited = vec![];
+    encoder::decode_with(code, |card, count| visited.push((card, count))).unwrap();
+    assert!(visited
+        .iter()
+        .map(|(card, count)| (card, *count))
+        .eq(deck.cards().iter().map(|c| (c.card(), c.count()))));
+
+    let mut buffer: [Option<CardCodeAndCount>; 40] = std::array::from_fn(|_| None);
+    let len = encoder::decode_into(code, &mut buffer).unwrap();
+    assert!(buffer[..len]
+        .iter()
+        .map(|slot| slot.as_ref().unwrap())
+        .eq(deck.cards()));
+    assert!(buffer[len..].iter().all(Option::is_none));
+
+    let mut small: [Option<CardCodeAndCount>; 4] = Default::default();
+    assert!(matches!(
+        encoder::decode_into(code, &mut small),
+        Err(LorError::BufferTooSmall { needed: 14 })
+    ));
+    assert!(small.iter().all(Option::is_none));
+
+    // a code missing its last byte only fails after its cards are decoded
+    let mut bytes = data_encoding::BASE32_NOPAD.decode(code.as_bytes()).unwrap();
+    bytes.pop();
+    let truncated = data_encoding::BASE32_NOPAD.encode(&bytes);
+    let mut empty: [Option<CardCodeAndCount>; 40] = std::array::from_fn(|_| None);
+    assert!(matches!(
+        encoder::decode_into(&truncated, &mut empty),
+        Err(LorError::VarintDecode(_))
+    ));
+    assert!(empty.iter().all(Option::is_none));
+}
+
 #[test]
 fn deck_version_is_the_minimum_library_version_that_supports_the_contained_factions() {
     fn extract_version_from_deck_code(code: &str) -> u8 {
@@ -419,10 +515,11 @@ fn deck_version_is_the_minimum_library_version_that_supports_the_contained_facti
 #[test]
 fn argument_exception_on_future_version() {
     let single_card_deck_with_version_10 = "DEAAABABAEFACAIBAAAQCAIFAEAQGCTP";
-    assert!(deck_from_code(&single_card_deck_with_version_10).is_err());
+    assert!(deck_from_code(single_card_deck_with_version_10).is_err());
 }
 
 #[test]
+#[cfg(feature = "serde")]
 fn deck_serialize_deserialize() {
     let deck = Deck::from_vec(vec![
         CardCodeAndCount::from_data("01SI015", 3).unwrap(),
@@ -452,6 +549,67 @@ fn deck_serialize_deserialize() {
     assert!(verify_rehydration(&deck, &deck_from_json.unwrap()));
 }
 
+#[test]
+fn find_codes_in_text() {
+    let code1 = "CEBAIAIFB4WDANQIAEAQGDAUDAQSIJZUAIAQCAIEAEAQKBIA";
+    let code2 = "CIBAEAIBAQTQMAIAAILSQLBNGUBACAIBFYDACAAHBEHR2IBLAEBACAIFAY";
+    let text = format!(
+        "New list: https://example.com/decks/code/{code1}?ref=x\n\
+         Also `{code2}` is GREAT. \
+         CONGRATULATIONSEVERYONE! x{code1} {code1}y"
+    );
+
+    let found: Vec<_> = encoder::find_codes(&text).collect();
+    assert_eq!(found.len(), 2);
+
+    assert_eq!(&text[found[0].0.clone()], code1);
+    assert!(verify_rehydration(
+        &found[0].1,
+        &encoder::deck_from_code(code1).unwrap()
+    ));
+    assert_eq!(&text[found[1].0.clone()], code2);
+
+    assert_eq!(encoder::find_codes("").count(), 0);
+    assert_eq!(
+        encoder::find_codes("CEBAIAIFB4WDANQIAEAQGDAUDAQSIJZUAIAQCAIEAEAQKBIAA").count(),
+        0
+    );
+}
+
+#[test]
+fn deck_count_and_set_count() {
+    let mut deck: Deck = [("01SI015", 1), ("01SI044", 3), ("01SI015", 2)]
+        .iter()
+        .collect();
+    let card = CardCodeAndCount::from_data("01SI015", 1)
+        .unwrap()
+        .card()
+        .clone();
+    let missing = CardCodeAndCount::from_data("01DE001", 1)
+        .unwrap()
+        .card()
+        .clone();
+    assert_eq!(deck.count(&card), 3);
+    assert_eq!(deck.count(&missing), 0);
+
+    deck.set_count(card.clone(), 2);
+    assert_eq!(deck, Deck::from_iter(&[("01SI015", 2), ("01SI044", 3)]));
+
+    deck.set_count(missing.clone(), 1);
+    assert_eq!(
+        deck,
+        Deck::from_iter(&[("01SI015", 2), ("01SI044", 3), ("01DE001", 1)])
+    );
+
+    deck.set_count(card.clone(), 0);
+    deck.set_count(missing, -1);
+    assert_eq!(deck, Deck::from_iter(&[("01SI044", 3)]));
+    assert_eq!(deck.count(&card), 0);
+
+    let large = Deck::from_iter(&[("01SI015", i32::MAX), ("01SI015", i32::MAX)]);
+    assert_eq!(large.count(&card), i32::MAX);
+}
+
 fn verify_rehydration(d: &Deck, other: &Deck) -> bool {
     if d.cards().len() != other.cards().len() {
         return false;
@@ -0,0 +1,124 @@
+#![cfg(feature = "std")]
+
+use lordeckcodes::url::{UrlPattern, UrlRegistry, CODE_PLACEHOLDER, KNOWN_SITES};
+use lordeckcodes::{encoder, LorError};
+
+const CODE: &str = "CEBAIAIFB4WDANQIAEAQGDAUDAQSIJZUAIAQCAIEAEAQKBIA";
+
+#[test]
+fn url_extract_known_shapes() {
+    let registry = UrlRegistry::with_known_sites();
+
+    for url in [
+        format!("https://lor.mobalytics.gg/decks/code/{CODE}"),
+        format!("http://LOR.mobalytics.gg/decks/code/{CODE}/"),
+        format!("https://www.runeterra.ar/decks/code/{CODE}?lang=en#top"),
+    ] {
+        assert_eq!(registry.extract(&url), Some(CODE), "{url}");
+    }
+
+    assert_eq!(
+        registry.extract("https://lor.mobalytics.gg/decks/code/"),
+        None
+    );
+    assert_eq!(
+        registry.extract(&format!("https://example.com/decks/code/{CODE}")),
+        None
+    );
+    assert!(matches!(
+        registry.deck_from_url("https://example.com"),
+        Err(LorError::InvalidUrl)
+    ));
+}
+
+#[test]
+fn url_known_sites() {
+    assert!(UrlRegistry::new().patterns().is_empty());
+    assert!(UrlRegistry::default().patterns().is_empty());
+
+    let registry = UrlRegistry::with_known_sites();
+    assert_eq!(registry.patterns().len(), KNOWN_SITES.len());
+
+    let deck = encoder::deck_from_code(CODE).unwrap();
+    for (name, template) in KNOWN_SITES {
+        let url = registry.share_url(name, &deck).unwrap();
+        assert_eq!(url, template.replace(CODE_PLACEHOLDER, CODE));
+        assert_eq!(registry.extract(&url), Some(CODE), "{url}");
+    }
+}
+
+#[test]
+fn url_custom_patterns() {
+    let mut registry = UrlRegistry::new();
+    registry
+        .register(UrlPattern::new("query", "https://example.com/builder/?deck={code}").unwrap());
+    registry.register(UrlPattern::new("path", "https://example.org/d/{code}/view").unwrap());
+
+    let query = format!("https://example.com/builder?lang=fr&deck={CODE}");
+    assert_eq!(registry.extract(&query), Some(CODE));
+    assert_eq!(
+        registry.extract(&format!("https://example.org/d/{CODE}/view")),
+        Some(CODE)
+    );
+    assert_eq!(
+        registry.extract(&format!("https://example.org/d/{CODE}/edit")),
+        None
+    );
+
+    let deck = registry.deck_from_url(&query).unwrap();
+    assert_eq!(
+        registry.share_url("path", &deck).unwrap(),
+        format!(
+            "https://example.org/d/{}/view",
+            encoder::code_from_deck(&deck).unwrap()
+        )
+    );
+    assert!(registry.share_url("unknown", &deck).is_err());
+
+    assert!(UrlPattern::new("none", "https://example.com/").is_err());
+    assert!(UrlPattern::new("twice", "https://example.com/{code}/{code}").is_err());
+    assert!(UrlPattern::new("query", "https://example.com/?deck=x{code}").is_err());
+}
+
+#[test]
+fn url_query_and_fragment_patterns() {
+    let mut registry = UrlRegistry::new();
+    registry
+        .register(UrlPattern::new("query", "https://example.com/b?deck={code}&lang=en").unwrap());
+    registry.register(UrlPattern::new("hash", "https://example.net/builder#{code}").unwrap());
+    registry.register(
+        UrlPattern::new("hash-key", "https://example.org/b#lang=en&deck={code}").unwrap(),
+    );
+
+    for url in [
+        format!("https://example.com/b?deck={CODE}&lang=en"),
+        format!("https://example.com/b?lang=fr&deck={CODE}#top"),
+        format!("https://example.net/builder/#{CODE}"),
+        format!("https://example.org/b#deck={CODE}"),
+    ] {
+        assert_eq!(registry.extract(&url), Some(CODE), "{url}");
+    }
+
+    assert_eq!(registry.extract("https://example.net/builder"), None);
+    assert_eq!(registry.extract("https://example.net/builder#"), None);
+    assert_eq!(
+        registry.extract(&format!("https://example.net/other#{CODE}")),
+        None
+    );
+    assert_eq!(
+        registry.get("hash").unwrap().build(CODE),
+        format!("https://example.net/builder#{CODE}")
+    );
+
+    for template in [
+        "{code}://example.com/",
+        "https://{code}.example.com/decks",
+        "https://example.com/?{code}=deck",
+        "https://example.com/#deck=x{code}",
+    ] {
+        assert!(
+            matches!(UrlPattern::new("bad", template), Err(LorError::InvalidUrl)),
+            "{template}"
+        );
+    }
+}
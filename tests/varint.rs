@@ -0,0 +1,61 @@
+use lordeckcodes::varint::{self, VarintError};
+use lordeckcodes::{encoder, LorError};
+
+#[test]
+fn round_trip() {
+    for value in [
+        0,
+        1,
+        0x7F,
+        0x80,
+        0x3FFF,
+        0x4000,
+        0x0FFF_FFFF,
+        0x1000_0000,
+        u32::MAX,
+    ] {
+        let mut bytes = vec![];
+        varint::write_u32(&mut bytes, value);
+        assert!(bytes.len() <= varint::MAX_U32_LEN);
+
+        let mut input = bytes.into_iter();
+        assert_eq!(varint::read_u32(&mut input), Ok(value));
+        assert_eq!(input.next(), None);
+    }
+}
+
+#[test]
+fn malformed() {
+    let read = |bytes: &[u8]| varint::read_u32(&mut bytes.iter().copied());
+
+    assert_eq!(read(&[]), Err(VarintError::Truncated));
+    assert_eq!(read(&[0xFF, 0xFF]), Err(VarintError::Truncated));
+    assert_eq!(read(&[0x81, 0x00]), Err(VarintError::Overlong));
+    assert_eq!(read(&[0xFF, 0xFF, 0xFF, 0xFF, 0x0F]), Ok(u32::MAX));
+    assert_eq!(
+        read(&[0xFF, 0xFF, 0xFF, 0xFF, 0x1F]),
+        Err(VarintError::Overflow)
+    );
+    assert_eq!(
+        read(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x01]),
+        Err(VarintError::Overflow)
+    );
+}
+
+#[test]
+fn malformed_codes_are_rejected() {
+    let decode = |bytes: &[u8]| encoder::deck_from_code(data_encoding::BASE32_NOPAD.encode(bytes));
+
+    assert!(matches!(
+        decode(&[0x12, 0x01]),
+        Err(LorError::VarintDecode(VarintError::Truncated))
+    ));
+    assert!(matches!(
+        decode(&[0x12, 0x80, 0x00]),
+        Err(LorError::VarintDecode(VarintError::Overlong))
+    ));
+    assert!(matches!(
+        decode(&[0x12, 0x80, 0x80, 0x80, 0x80, 0x10]),
+        Err(LorError::VarintDecode(VarintError::Overflow))
+    ));
+}
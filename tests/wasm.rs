@@ -0,0 +1,25 @@
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use lordeckcodes::wasm::{decode_deck, encode_deck, normalize_code, validate_code};
+
+const CODE: &str = "CEBAIAIFB4WDANQIAEAQGDAUDAQSIJZUAIAQCAIEAEAQKBIA";
+
+#[wasm_bindgen_test]
+fn decode_encode() {
+    let cards = decode_deck(CODE).unwrap();
+    let array: &js_sys::Array = cards.unchecked_ref();
+    assert_eq!(array.length(), 14);
+
+    assert_eq!(encode_deck(cards).unwrap(), CODE);
+}
+
+#[wasm_bindgen_test]
+fn invalid_codes() {
+    assert!(decode_deck("not a code").is_err());
+    assert!(validate_code("not a code").is_some());
+    assert!(validate_code(CODE).is_none());
+    assert_eq!(normalize_code(&CODE.to_lowercase()).unwrap(), CODE);
+}